@@ -0,0 +1,219 @@
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use crate::{Core, CpuInfo, DistributionCore, Endianness, Fabricant};
+#[cfg(feature = "std")]
+use crate::{AddressBits, CacheInfo, CpuArchitecture, CpuInfoError, Hypervisor, Signature};
+
+/// Environment variable naming a JSON file that replaces live detection.
+#[cfg(all(feature = "std", feature = "serde"))]
+const OVERRIDE_ENV_VAR: &str = "CPU_INFO_OVERRIDE";
+
+/// Detection logic shared by every platform module.
+///
+/// The pure helpers are also used by [`crate::CpuInfoBuilder`], so they stay
+/// available without `std`.
+impl CpuInfo {
+    /// Detects the CPU architecture using Rust's built-in constants.
+    ///
+    /// This method is compile-time safe and doesn't require any system calls.
+    #[cfg(feature = "std")]
+    pub(crate) fn get_architecture() -> CpuArchitecture {
+        match std::env::consts::ARCH {
+            "x86_64" => CpuArchitecture::X86_64,
+            "aarch64" => CpuArchitecture::ARM64,
+            "arm" => CpuArchitecture::ARM,
+            "x86" => CpuArchitecture::X86,
+            "riscv32" => CpuArchitecture::RISCV32,
+            "riscv64" => CpuArchitecture::RISCV64,
+            "s390x" => CpuArchitecture::S390X,
+            "powerpc" => CpuArchitecture::PowerPC,
+            "powerpc64" => CpuArchitecture::PowerPC64,
+            "loongarch64" => CpuArchitecture::LoongArch64,
+            _ => CpuArchitecture::Unknown,
+        }
+    }
+
+    /// Detects the byte order from the compile-time target.
+    pub(crate) fn get_endianness() -> Endianness {
+        if cfg!(target_endian = "big") {
+            Endianness::Big
+        } else {
+            Endianness::Little
+        }
+    }
+
+    /// Detects the processor signature.
+    ///
+    /// Only x86/x86_64 have a CPUID signature; other architectures return `None`.
+    #[cfg(feature = "std")]
+    pub(crate) fn get_signature() -> Option<Signature> {
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            Self::cpuid_usable().then(Self::get_signature_cpuid)
+        }
+
+        #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+        {
+            None
+        }
+    }
+
+    /// Detects the physical and virtual address widths.
+    ///
+    /// Only x86/x86_64 expose this through CPUID; other architectures return `None`.
+    #[cfg(feature = "std")]
+    pub(crate) fn get_address_bits() -> Option<AddressBits> {
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            Self::get_address_bits_cpuid()
+        }
+
+        #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+        {
+            None
+        }
+    }
+
+    /// Loads the `CpuInfo` stored in the file named by `CPU_INFO_OVERRIDE`.
+    ///
+    /// Returns `None` when the variable is unset or the `serde` feature is
+    /// disabled, so the caller goes on with live detection.
+    #[cfg(feature = "std")]
+    pub(crate) fn from_override() -> Option<Result<CpuInfo, CpuInfoError>> {
+        #[cfg(feature = "serde")]
+        {
+            let path = std::env::var_os(OVERRIDE_ENV_VAR)?;
+            Some(
+                std::fs::read_to_string(&path)
+                    .map_err(CpuInfoError::Os)
+                    .and_then(|json| {
+                        serde_json::from_str(&json).map_err(|e| {
+                            CpuInfoError::ParseError(format!("{}: {}", OVERRIDE_ENV_VAR, e))
+                        })
+                    }),
+            )
+        }
+
+        #[cfg(not(feature = "serde"))]
+        {
+            None
+        }
+    }
+
+    /// Detects the cache hierarchy.
+    ///
+    /// Only x86/x86_64 expose this through CPUID; other architectures return
+    /// an empty list.
+    #[cfg(feature = "std")]
+    pub(crate) fn get_caches() -> Vec<CacheInfo> {
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            Self::get_caches_cpuid()
+        }
+
+        #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+        {
+            Vec::new()
+        }
+    }
+
+    /// Detects the hypervisor.
+    ///
+    /// Only x86/x86_64 expose this through CPUID; other architectures return `None`.
+    #[cfg(feature = "std")]
+    pub(crate) fn get_hypervisor() -> Option<Hypervisor> {
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            Some(Self::get_hypervisor_cpuid())
+        }
+
+        #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+        {
+            None
+        }
+    }
+
+    /// Maps an x86 vendor string (CPUID leaf 0, or `vendor_id` in procfs).
+    ///
+    /// Surrounding whitespace is ignored, since some vendors pad their
+    /// 12-byte string (`"  Shanghai  "`, `"VIA VIA VIA "`).
+    pub(crate) fn fabricant_from_vendor_id(vendor: &str) -> Fabricant {
+        match vendor.trim() {
+            "GenuineIntel" => Fabricant::Intel,
+            "AuthenticAMD" | "AMDisbetter!" => Fabricant::Amd,
+            "HygonGenuine" => Fabricant::Hygon,
+            "CentaurHauls" | "VIA VIA VIA" => Fabricant::Via,
+            "Shanghai" => Fabricant::Zhaoxin,
+            vendor => Fabricant::Other(vendor.to_string()),
+        }
+    }
+
+    /// Infers whether SMT is active from the logical and physical core counts.
+    ///
+    /// Used when the platform has no direct SMT indicator.
+    pub(crate) fn smt_from_counts(logical: Option<usize>, physical: Option<usize>) -> Option<bool> {
+        match (logical, physical) {
+            (Some(logical), Some(physical)) => Some(logical > physical),
+            _ => None,
+        }
+    }
+
+    /// Detects CPU core distribution by analyzing core capacities or frequencies.
+    ///
+    /// Returns `Lineal` if all cores have the same frequency (traditional CPUs),
+    /// or `Hybrid` if cores have different frequencies (e.g., Intel 12th gen+, some ARM).
+    /// Only the maximum frequency is compared, and cores within
+    /// [`DistributionCore::FREQUENCY_TOLERANCE_MHZ`] of each other count as
+    /// equal; `Lineal` then carries the highest of them plus the base
+    /// frequency reported by the cores. Cores at 0 MHz (usually offline) are
+    /// ignored when comparing frequencies. `Hybrid` cores are kept in
+    /// ascending id order, so each logical id stays next to its own frequency.
+    ///
+    /// When every core reports a capacity, capacities are compared instead
+    /// (within [`DistributionCore::CAPACITY_TOLERANCE_PERCENT`]), since
+    /// big and little cores can run at the same clock.
+    pub(crate) fn detect_distribution(cores: &[Core]) -> DistributionCore {
+        let capacities: Option<Vec<u32>> = cores.iter().map(|c| c.capacity).collect();
+
+        // If we have no core information, return Lineal with 0 MHz
+        if cores.is_empty() || (cores.iter().all(|c| c.speed_mhz == 0) && capacities.is_none()) {
+            return DistributionCore::Lineal { mhz: 0, base_mhz: 0 };
+        }
+
+        let mut cores = cores.to_vec();
+        cores.sort_by_key(|c| c.id);
+
+        // Offline cores lose their cpufreq directory and report 0 MHz; they
+        // say nothing about the clock, so leave them out of the comparison
+        let max_mhz = cores.iter().map(|c| c.speed_mhz).max().unwrap_or(0);
+        let min_mhz = cores
+            .iter()
+            .map(|c| c.speed_mhz)
+            .filter(|&mhz| mhz > 0)
+            .min()
+            .unwrap_or(0);
+
+        let all_same = match capacities {
+            // Capacity-based detection
+            Some(capacities) => {
+                let max = capacities.iter().copied().max().unwrap_or(0);
+                let min = capacities.iter().copied().min().unwrap_or(0);
+                (max - min).saturating_mul(100) <= max.saturating_mul(DistributionCore::CAPACITY_TOLERANCE_PERCENT)
+            }
+            // Frequency-based detection
+            None => max_mhz - min_mhz <= DistributionCore::FREQUENCY_TOLERANCE_MHZ,
+        };
+        
+        if all_same {
+            return DistributionCore::Lineal { 
+                mhz: max_mhz,
+                base_mhz: cores.iter().map(|c| c.base_mhz).max().unwrap_or(0),
+            };
+        }
+
+        // If not uniform, store all cores with their individual frequencies
+        DistributionCore::Hybrid { 
+            groups: cores 
+        }
+    }
+}
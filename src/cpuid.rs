@@ -0,0 +1,355 @@
+use crate::{AddressBits,CacheInfo,CpuFeature,CpuInfo,CpuInfoError,CpuidResult,Fabricant,Hypervisor,Signature};
+
+/// CPUID-based detection shared by every platform module on x86/x86_64.
+impl CpuInfo {
+    /// Checks that CPUID answers with plausible data before it is relied on.
+    ///
+    /// Some sandboxes and emulators return zeros or garbage for every leaf.
+    /// The leaf 0 vendor string must be printable ASCII and leaf 1 must be
+    /// implemented; otherwise callers fall back to the OS. A CPUID that traps
+    /// (CPUID faulting enabled for the process) can't be recovered here.
+    pub(crate) fn cpuid_usable() -> bool {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::__cpuid;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::__cpuid;
+
+        let result = __cpuid(0);
+
+        let mut vendor = [0u8; 12];
+        vendor[0..4].copy_from_slice(&result.ebx.to_le_bytes());
+        vendor[4..8].copy_from_slice(&result.edx.to_le_bytes());
+        vendor[8..12].copy_from_slice(&result.ecx.to_le_bytes());
+
+        result.eax >= 1
+            && vendor.iter().all(|&b| b == b' ' || b.is_ascii_graphic())
+            && vendor.iter().any(|b| b.is_ascii_alphabetic())
+    }
+
+    /// Runs one CPUID query after checking that `leaf` is implemented.
+    ///
+    /// The first leaf of each range (`leaf & 0xffff0000`) reports the
+    /// highest leaf of that range in EAX.
+    pub(crate) fn cpuid_checked(leaf: u32, subleaf: u32) -> Option<CpuidResult> {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::{__cpuid, __cpuid_count};
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::{__cpuid, __cpuid_count};
+
+        if !Self::cpuid_usable() {
+            return None;
+        }
+
+        let base = leaf & 0xffff_0000;
+        let max_leaf = __cpuid(base).eax;
+        if max_leaf < base || leaf > max_leaf {
+            return None;
+        }
+
+        let result = __cpuid_count(leaf, subleaf);
+        Some(CpuidResult {
+            eax: result.eax,
+            ebx: result.ebx,
+            ecx: result.ecx,
+            edx: result.edx,
+        })
+    }
+
+    /// Uses CPUID instruction to detect CPU vendor on x86/x86_64.
+    ///
+    /// This method is language-independent and works regardless of system locale.
+    pub(crate) fn get_fabricant_cpuid() -> Result<Fabricant, CpuInfoError> {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::__cpuid;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::__cpuid;
+
+        if !Self::cpuid_usable() {
+            return Err(CpuInfoError::CpuidUnsupported);
+        }
+
+        let result = __cpuid(0);
+
+        // EBX, EDX, ECX contain the vendor string (12 bytes)
+        let mut vendor = [0u8; 12];
+        vendor[0..4].copy_from_slice(&result.ebx.to_le_bytes());
+        vendor[4..8].copy_from_slice(&result.edx.to_le_bytes());
+        vendor[8..12].copy_from_slice(&result.ecx.to_le_bytes());
+
+        Ok(Self::fabricant_from_vendor_id(&String::from_utf8_lossy(&vendor)))
+    }
+
+    /// Uses CPUID extended functions to get the CPU brand string on x86/x86_64.
+    pub(crate) fn get_model_cpuid() -> Result<String, CpuInfoError> {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::__cpuid;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::__cpuid;
+
+        if !Self::cpuid_usable() {
+            return Err(CpuInfoError::CpuidUnsupported);
+        }
+
+        let ext_result = __cpuid(0x80000000);
+        if ext_result.eax < 0x80000004 {
+            return Err(CpuInfoError::CpuidUnsupported);
+        }
+
+        let mut brand = [0u8; 48];
+
+        // Read the 3 registers containing the brand string
+        for i in 0..3 {
+            let result = __cpuid(0x80000002 + i);
+            let offset = i as usize * 16;
+            brand[offset..offset + 4].copy_from_slice(&result.eax.to_le_bytes());
+            brand[offset + 4..offset + 8].copy_from_slice(&result.ebx.to_le_bytes());
+            brand[offset + 8..offset + 12].copy_from_slice(&result.ecx.to_le_bytes());
+            brand[offset + 12..offset + 16].copy_from_slice(&result.edx.to_le_bytes());
+        }
+
+        // Short brand strings are padded with NULs
+        let model = String::from_utf8_lossy(&brand)
+            .trim_matches(|c: char| c == '\0' || c.is_whitespace())
+            .to_string();
+
+        if model.is_empty() {
+            // The brand string leaves are present but left blank
+            Err(CpuInfoError::CpuidUnsupported)
+        } else {
+            Ok(model)
+        }
+    }
+
+    /// Detects instruction-set extensions with CPUID leaves 1 and 7.
+    ///
+    /// AVX-family features additionally require the OS to save the extended
+    /// register state (checked through `XGETBV`), otherwise they can't be used.
+    pub(crate) fn get_features_cpuid() -> Vec<CpuFeature> {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::{__cpuid, __cpuid_count, _xgetbv};
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::{__cpuid, __cpuid_count, _xgetbv};
+
+        if !Self::cpuid_usable() {
+            return Vec::new();
+        }
+
+        let max_leaf = __cpuid(0).eax;
+        let leaf1 = __cpuid(1);
+        let (leaf7_ebx, leaf7_ecx, leaf7_1_eax) = if max_leaf >= 7 {
+            let leaf7 = __cpuid_count(7, 0);
+            // Leaf 7 EAX holds the highest supported subleaf
+            let leaf7_1_eax = if leaf7.eax >= 1 { __cpuid_count(7, 1).eax } else { 0 };
+            (leaf7.ebx, leaf7.ecx, leaf7_1_eax)
+        } else {
+            (0, 0, 0)
+        };
+
+        // OSXSAVE (leaf 1 ECX bit 27) guarantees XGETBV is available
+        let xcr0 = if leaf1.ecx & (1 << 27) != 0 {
+            unsafe { _xgetbv(0) }
+        } else {
+            0
+        };
+
+        Self::decode_features(leaf1.ecx, leaf1.edx, leaf7_ebx, leaf7_ecx, leaf7_1_eax, xcr0)
+    }
+
+    /// Decodes CPUID feature bits into a list of `CpuFeature`s.
+    ///
+    /// Takes leaf 1 ECX/EDX, leaf 7 subleaf 0 EBX/ECX, leaf 7 subleaf 1 EAX
+    /// and the XCR0 register.
+    fn decode_features(
+        leaf1_ecx: u32,
+        leaf1_edx: u32,
+        leaf7_ebx: u32,
+        leaf7_ecx: u32,
+        leaf7_1_eax: u32,
+        xcr0: u64,
+    ) -> Vec<CpuFeature> {
+        let bit = |reg: u32, n: u32| reg & (1 << n) != 0;
+
+        // XMM|YMM state for AVX, plus opmask|ZMM state for AVX-512
+        let os_avx = xcr0 & 0x6 == 0x6;
+        let os_avx512 = xcr0 & 0xe6 == 0xe6;
+
+        let checks = [
+            (CpuFeature::Sse, bit(leaf1_edx, 25)),
+            (CpuFeature::Sse2, bit(leaf1_edx, 26)),
+            (CpuFeature::Sse3, bit(leaf1_ecx, 0)),
+            (CpuFeature::Ssse3, bit(leaf1_ecx, 9)),
+            (CpuFeature::Sse4_1, bit(leaf1_ecx, 19)),
+            (CpuFeature::Sse4_2, bit(leaf1_ecx, 20)),
+            (CpuFeature::Popcnt, bit(leaf1_ecx, 23)),
+            (CpuFeature::Aes, bit(leaf1_ecx, 25)),
+            (CpuFeature::Avx, os_avx && bit(leaf1_ecx, 28)),
+            (CpuFeature::Avx2, os_avx && bit(leaf7_ebx, 5)),
+            (CpuFeature::Fma, os_avx && bit(leaf1_ecx, 12)),
+            (CpuFeature::Bmi1, bit(leaf7_ebx, 3)),
+            (CpuFeature::Bmi2, bit(leaf7_ebx, 8)),
+            (CpuFeature::Avx512F, os_avx512 && bit(leaf7_ebx, 16)),
+            (CpuFeature::Avx512Dq, os_avx512 && bit(leaf7_ebx, 17)),
+            (CpuFeature::Avx512Bw, os_avx512 && bit(leaf7_ebx, 30)),
+            (CpuFeature::Avx512Vl, os_avx512 && bit(leaf7_ebx, 31)),
+            (CpuFeature::Avx512Vbmi, os_avx512 && bit(leaf7_ecx, 1)),
+            (CpuFeature::Avx512Vnni, os_avx512 && bit(leaf7_ecx, 11)),
+            (CpuFeature::Avx512Bf16, os_avx512 && bit(leaf7_1_eax, 5)),
+            (CpuFeature::Rdrand, bit(leaf1_ecx, 30)),
+            (CpuFeature::Rdseed, bit(leaf7_ebx, 18)),
+        ];
+
+        checks
+            .into_iter()
+            .filter(|(_, present)| *present)
+            .map(|(feature, _)| feature)
+            .collect()
+    }
+
+    /// Reads the processor signature from CPUID leaf 1 EAX.
+    pub(crate) fn get_signature_cpuid() -> Signature {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::__cpuid;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::__cpuid;
+
+        Signature::from_eax(__cpuid(1).eax)
+    }
+
+    /// Detects the hypervisor with CPUID.
+    ///
+    /// Leaf 1 ECX bit 31 is set by every hypervisor; its vendor string is
+    /// then read from leaf 0x40000000 (EBX, ECX, EDX).
+    pub(crate) fn get_hypervisor_cpuid() -> Hypervisor {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::__cpuid;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::__cpuid;
+
+        if !Self::cpuid_usable() || __cpuid(1).ecx & (1 << 31) == 0 {
+            return Hypervisor::None;
+        }
+
+        let result = __cpuid(0x40000000);
+        let mut vendor = [0u8; 12];
+        vendor[0..4].copy_from_slice(&result.ebx.to_le_bytes());
+        vendor[4..8].copy_from_slice(&result.ecx.to_le_bytes());
+        vendor[8..12].copy_from_slice(&result.edx.to_le_bytes());
+
+        match &vendor {
+            b"KVMKVMKVM\0\0\0" => Hypervisor::KVM,
+            b"VMwareVMware" => Hypervisor::VMware,
+            b"Microsoft Hv" => Hypervisor::HyperV,
+            b"XenVMMXenVMM" => Hypervisor::Xen,
+            b"TCGTCGTCGTCG" => Hypervisor::QEMU,
+            _ => Hypervisor::Other(
+                String::from_utf8_lossy(&vendor)
+                    .trim_matches(|c: char| c == '\0' || c.is_whitespace())
+                    .to_string(),
+            ),
+        }
+    }
+
+    /// Reads the address widths from CPUID leaf 0x80000008.
+    ///
+    /// EAX bits 7:0 hold the physical width and bits 15:8 the linear one.
+    /// Returns `None` if the leaf isn't implemented.
+    pub(crate) fn get_address_bits_cpuid() -> Option<AddressBits> {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::__cpuid;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::__cpuid;
+
+        if !Self::cpuid_usable() || __cpuid(0x80000000).eax < 0x80000008 {
+            return None;
+        }
+
+        let eax = __cpuid(0x80000008).eax;
+        Some(AddressBits {
+            physical: (eax & 0xff) as u8,
+            virtual_: ((eax >> 8) & 0xff) as u8,
+        })
+    }
+
+    /// Reads the cache hierarchy from the deterministic cache parameters leaf.
+    ///
+    /// Intel reports it in leaf 4; AMD uses the identical leaf 0x8000001D when
+    /// topology extensions are available. Every subleaf describes one cache
+    /// level and type as seen from the current core, until a null entry.
+    /// Returns an empty list if neither leaf is implemented.
+    pub(crate) fn get_caches_cpuid() -> Vec<CacheInfo> {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::{__cpuid, __cpuid_count};
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::{__cpuid, __cpuid_count};
+
+        if !Self::cpuid_usable() {
+            return Vec::new();
+        }
+
+        // TOPOEXT is leaf 0x80000001 ECX bit 22
+        let max_ext_leaf = __cpuid(0x80000000).eax;
+        let leaf = if max_ext_leaf >= 0x8000001D && __cpuid(0x80000001).ecx & (1 << 22) != 0 {
+            0x8000001D
+        } else if __cpuid(0).eax >= 4 {
+            4
+        } else {
+            return Vec::new();
+        };
+
+        // Bounded in case a hypervisor never returns the null entry
+        let mut caches: Vec<CacheInfo> = (0..64)
+            .map(|subleaf| __cpuid_count(leaf, subleaf))
+            .map_while(|result| CacheInfo::from_cpuid(result.eax, result.ebx, result.ecx))
+            .collect();
+
+        caches.sort_by_key(|c| c.level);
+        caches
+    }
+
+    /// Counts TSC ticks while sleeping for `duration` and converts them to MHz.
+    ///
+    /// Requires an invariant TSC (leaf 0x80000007 EDX bit 8); otherwise the
+    /// rate follows power states and `None` is returned.
+    #[cfg(feature = "estimate")]
+    pub(crate) fn measure_tsc_mhz(duration: std::time::Duration) -> Option<u32> {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::{__cpuid, _rdtsc};
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::{__cpuid, _rdtsc};
+
+        if !Self::cpuid_usable()
+            || __cpuid(0x80000000).eax < 0x80000007
+            || __cpuid(0x80000007).edx & (1 << 8) == 0
+        {
+            return None;
+        }
+
+        let start = std::time::Instant::now();
+        let start_ticks = unsafe { _rdtsc() };
+        std::thread::sleep(duration);
+        let ticks = unsafe { _rdtsc() }.wrapping_sub(start_ticks);
+        let elapsed = start.elapsed().as_secs_f64();
+
+        if elapsed <= 0.0 {
+            return None;
+        }
+        let mhz = (ticks as f64 / elapsed / 1_000_000.0).round();
+        (mhz >= 1.0 && mhz <= f64::from(u32::MAX)).then_some(mhz as u32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_features_reads_rdrand_and_rdseed() {
+        let features = CpuInfo::decode_features(1 << 30, 0, 1 << 18, 0, 0, 0);
+        assert!(features.contains(&CpuFeature::Rdrand));
+        assert!(features.contains(&CpuFeature::Rdseed));
+
+        let features = CpuInfo::decode_features(!(1 << 30), 0, !(1 << 18), 0, 0, 0);
+        assert!(!features.contains(&CpuFeature::Rdrand));
+        assert!(!features.contains(&CpuFeature::Rdseed));
+    }
+}
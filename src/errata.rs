@@ -0,0 +1,90 @@
+use alloc::vec::Vec;
+use crate::{CpuInfo, Fabricant};
+
+/// A published erratum and the range of signatures it affects.
+struct Erratum {
+    /// Vendor identifier, e.g. `"SKL150"` or `"AMD-1386"`
+    id: &'static str,
+    fabricant: Fabricant,
+    family: u16,
+    /// First affected `(model, stepping)`, inclusive
+    first: (u16, u8),
+    /// Last affected `(model, stepping)`, inclusive
+    last: (u16, u8),
+}
+
+/// Well-known errata, keyed by signature.
+///
+/// Intel ids come from the processor specification updates, AMD ids from
+/// the revision guides; the AMD ranges are the ones the Linux kernel checks.
+/// Keep entries grouped by vendor and sorted by family.
+const KNOWN_ERRATA: &[Erratum] = &[
+    // Short loops using AH/BH/CH/DH may misbehave with Hyper-Threading
+    Erratum { id: "SKL150", fabricant: Fabricant::Intel, family: 0x6, first: (0x4E, 0x0), last: (0x4E, 0xF) },
+    Erratum { id: "SKL150", fabricant: Fabricant::Intel, family: 0x6, first: (0x5E, 0x0), last: (0x5E, 0xF) },
+    Erratum { id: "KBL095", fabricant: Fabricant::Intel, family: 0x6, first: (0x8E, 0x9), last: (0x8E, 0x9) },
+    Erratum { id: "KBL095", fabricant: Fabricant::Intel, family: 0x6, first: (0x9E, 0x9), last: (0x9E, 0x9) },
+    // C1E may stop the local APIC timer
+    Erratum { id: "AMD-400", fabricant: Fabricant::Amd, family: 0xF, first: (0x41, 0x2), last: (0xFF, 0xF) },
+    Erratum { id: "AMD-400", fabricant: Fabricant::Amd, family: 0x10, first: (0x02, 0x1), last: (0xFF, 0xF) },
+    // Page table changes without a TLB flush may raise a machine check
+    Erratum { id: "AMD-383", fabricant: Fabricant::Amd, family: 0x10, first: (0x00, 0x0), last: (0xFF, 0xF) },
+    // The instructions-retired counter (IRPERF) may be inaccurate
+    Erratum { id: "AMD-1054", fabricant: Fabricant::Amd, family: 0x17, first: (0x00, 0x0), last: (0x2F, 0xF) },
+    // XSAVES may malfunction in some circumstances
+    Erratum { id: "AMD-1386", fabricant: Fabricant::Amd, family: 0x17, first: (0x01, 0x0), last: (0x2F, 0xF) },
+    Erratum { id: "AMD-1386", fabricant: Fabricant::Amd, family: 0x17, first: (0x50, 0x0), last: (0x5F, 0xF) },
+];
+
+/// Matching of the processor signature against known errata.
+impl CpuInfo {
+    /// Returns the ids of well-known errata affecting this CPU's stepping.
+    ///
+    /// The detected [`Signature`](crate::Signature) and vendor are matched
+    /// against a small built-in table that grows over time; ids follow the
+    /// vendor documents (`"SKL150"` from Intel's specification updates,
+    /// `"AMD-1386"` for erratum 1386 of AMD's revision guides). Only the
+    /// signature is checked, so an erratum fixed by a microcode update is
+    /// still listed. Returns an empty vec when nothing matches or the
+    /// signature is unknown.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cpu_info::{CpuInfo, Fabricant, Signature};
+    ///
+    /// // Intel Core i7-6700K (Skylake)
+    /// let skylake = CpuInfo {
+    ///     fabricant: Fabricant::Intel,
+    ///     signature: Some(Signature { family: 6, model: 94, stepping: 3 }),
+    ///     ..CpuInfo::default()
+    /// };
+    /// assert_eq!(skylake.known_errata(), ["SKL150"]);
+    ///
+    /// // AMD Ryzen 7 1700 (Zen)
+    /// let zen = CpuInfo {
+    ///     fabricant: Fabricant::Amd,
+    ///     signature: Some(Signature { family: 0x17, model: 0x01, stepping: 1 }),
+    ///     ..CpuInfo::default()
+    /// };
+    /// assert_eq!(zen.known_errata(), ["AMD-1054", "AMD-1386"]);
+    ///
+    /// assert!(CpuInfo::default().known_errata().is_empty());
+    /// ```
+    pub fn known_errata(&self) -> Vec<&'static str> {
+        let Some(signature) = self.signature else {
+            return Vec::new();
+        };
+        let current = (signature.model, signature.stepping);
+
+        KNOWN_ERRATA
+            .iter()
+            .filter(|erratum| {
+                erratum.fabricant == self.fabricant
+                    && erratum.family == signature.family
+                    && (erratum.first..=erratum.last).contains(&current)
+            })
+            .map(|erratum| erratum.id)
+            .collect()
+    }
+}
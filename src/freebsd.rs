@@ -0,0 +1,307 @@
+use crate::{Core,CpuFeature,CpuInfo,CpuInfoError,DistributionCore,Fabricant,IdleState,SmtControl,Vulnerability};
+
+#[cfg(feature = "freebsd")]
+impl CpuInfo {
+    /// Creates a new `CpuInfo` instance by detecting all CPU information.
+    ///
+    /// On FreeBSD every value is queried through `sysctlbyname`, so no files
+    /// or external commands are involved.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cpu_info::{CpuInfo, DistributionCore};
+    ///
+    /// let cpu_info = CpuInfo::new();
+    /// match cpu_info.distribution {
+    ///     DistributionCore::Lineal { mhz, .. } => {
+    ///         println!("Uniform CPU with all cores at {} MHz", mhz);
+    ///     }
+    ///     DistributionCore::Hybrid { ref groups } => {
+    ///         println!("Hybrid CPU with {} cores at different speeds", groups.len());
+    ///     }
+    /// }
+    /// ```
+    pub fn new() -> Self {
+        if let Some(Ok(info)) = Self::from_override() {
+            return info;
+        }
+
+        let total_logical_cores = Self::get_total_logical_cores_freebsd().ok();
+        let total_physical_cores = Self::sysctl_u32("kern.smp.cores")
+            .ok()
+            .map(|n| n as usize);
+
+        Self {
+            architecture: Self::get_architecture(),
+            endianness: Self::get_endianness(),
+            fabricant: Self::get_fabricant_freebsd().unwrap_or(Fabricant::Unknown),
+            model: Self::get_model_freebsd().unwrap_or("Unknown".to_string()),
+            total_logical_cores,
+            // FreeBSD has no CPU hotplug; every started CPU is online
+            online_logical_cores: total_logical_cores,
+            total_physical_cores,
+            total_sockets: None,
+            smt_enabled: Self::smt_from_counts(total_logical_cores, total_physical_cores),
+            distribution: Self::get_distribution_freebsd(total_logical_cores),
+            caches: Self::get_caches(),
+            features: Self::get_features_freebsd(),
+            signature: Self::get_signature(),
+            microcode: None,
+            address_bits: Self::get_address_bits(),
+            hypervisor: Self::get_hypervisor(),
+            numa_nodes: Vec::new(),
+            clusters: Vec::new(),
+        }
+    }
+
+    /// Creates a new `CpuInfo` instance, reporting why detection failed.
+    ///
+    /// Unlike [`CpuInfo::new`], this returns [`CpuInfoError::Os`] when one of
+    /// the required `sysctl` values cannot be read.
+    pub fn try_new() -> Result<Self, CpuInfoError> {
+        if let Some(result) = Self::from_override() {
+            return result;
+        }
+
+        let total_logical_cores = Some(Self::get_total_logical_cores_freebsd()?);
+        let total_physical_cores = Self::sysctl_u32("kern.smp.cores")
+            .ok()
+            .map(|n| n as usize);
+
+        Ok(Self {
+            architecture: Self::get_architecture(),
+            endianness: Self::get_endianness(),
+            fabricant: Self::get_fabricant_freebsd()?,
+            model: Self::get_model_freebsd()?,
+            total_logical_cores,
+            // FreeBSD has no CPU hotplug; every started CPU is online
+            online_logical_cores: total_logical_cores,
+            total_physical_cores,
+            total_sockets: None,
+            smt_enabled: Self::smt_from_counts(total_logical_cores, total_physical_cores),
+            distribution: Self::get_distribution_freebsd(total_logical_cores),
+            caches: Self::get_caches(),
+            features: Self::get_features_freebsd(),
+            signature: Self::get_signature(),
+            microcode: None,
+            address_bits: Self::get_address_bits(),
+            hypervisor: Self::get_hypervisor(),
+            numa_nodes: Vec::new(),
+            clusters: Vec::new(),
+        })
+    }
+
+    /// Re-reads the current frequency of every known core.
+    ///
+    /// Only `dev.cpu.N.freq` is queried again; every other field is left
+    /// untouched. It is a no-op for `DistributionCore::Lineal`, which doesn't
+    /// track current frequencies.
+    pub fn refresh_frequencies(&mut self) {
+        if let DistributionCore::Hybrid { groups } = &mut self.distribution {
+            for core in groups {
+                core.current_mhz = Self::sysctl_u32(&format!("dev.cpu.{}.freq", core.id))
+                    .unwrap_or(0);
+            }
+        }
+    }
+
+    /// Re-reads how many logical cores are online.
+    ///
+    /// FreeBSD doesn't support CPU hotplug, so `online_logical_cores` always
+    /// equals the total and this is a no-op.
+    pub fn refresh_online(&mut self) {}
+
+    /// Reads the current CPU temperature in degrees Celsius.
+    ///
+    /// Requires the `coretemp` or `amdtemp` kernel module, which expose
+    /// `dev.cpu.N.temperature` in tenths of a kelvin. The hottest core is
+    /// returned, or `None` if no core reports a temperature.
+    pub fn package_temperature_celsius() -> Option<f32> {
+        let count = Self::get_total_logical_cores_freebsd().ok()?;
+
+        (0..count)
+            .filter_map(|id| Self::sysctl_u32(&format!("dev.cpu.{}.temperature", id)).ok())
+            .max()
+            .map(|decikelvin| decikelvin as f32 / 10.0 - 273.15)
+    }
+
+    /// Reads the configured package power limit in watts.
+    ///
+    /// FreeBSD has no `sysctl` for the package power limit, so this always
+    /// returns `None`.
+    pub fn power_limit_watts() -> Option<f64> {
+        None
+    }
+
+    /// Returns `true` if every core uses the `performance` cpufreq governor.
+    ///
+    /// cpufreq governors are Linux-only, so this always returns `false` on
+    /// FreeBSD.
+    pub fn all_cores_performance() -> bool {
+        false
+    }
+
+    /// Returns `true` if any core exposes a Linux `cpufreq` directory.
+    ///
+    /// Always `false` on FreeBSD.
+    pub fn has_cpufreq() -> bool {
+        false
+    }
+
+    /// Returns the active cpufreq scaling driver.
+    ///
+    /// Always `None` on FreeBSD, which has no cpufreq drivers.
+    pub fn scaling_driver() -> Option<String> {
+        None
+    }
+
+    /// Reads whether SMT (Hyper-Threading) can be switched at runtime.
+    ///
+    /// Only read from Linux's `smt/control`; always `None` on FreeBSD.
+    pub fn smt_control() -> Option<SmtControl> {
+        None
+    }
+
+    /// Returns whether turbo boost is currently enabled.
+    ///
+    /// Only read from Linux's `intel_pstate` and `cpufreq` controls; always
+    /// `None` on FreeBSD.
+    pub fn turbo_enabled() -> Option<bool> {
+        None
+    }
+
+    /// Returns the logical cores isolated from the scheduler with `isolcpus`.
+    ///
+    /// `isolcpus` is a Linux boot option, so this always returns an empty vec
+    /// on FreeBSD.
+    pub fn isolated_cores() -> Vec<u32> {
+        Vec::new()
+    }
+
+    /// Returns the groups of logical cores sharing a cache at `level`.
+    ///
+    /// Sharing lists are only read from Linux sysfs, so this always returns
+    /// an empty vec on FreeBSD.
+    pub fn cores_sharing_cache(_level: u8) -> Vec<Vec<u32>> {
+        Vec::new()
+    }
+
+    /// Reads the kernel's status for every known CPU vulnerability.
+    ///
+    /// Only read from Linux's `vulnerabilities/` directory, so this always
+    /// returns an empty vec on FreeBSD.
+    pub fn vulnerabilities() -> Vec<Vulnerability> {
+        Vec::new()
+    }
+
+    /// Reads the idle states (C-states) the kernel can put cpu0 into.
+    ///
+    /// Only read from Linux's `cpuidle`, so this always returns an empty vec
+    /// on FreeBSD.
+    pub fn idle_states() -> Vec<IdleState> {
+        Vec::new()
+    }
+
+    /// Returns the number of CPUs this process can effectively use.
+    ///
+    /// Cgroup CPU quotas are Linux-only, so on FreeBSD this is
+    /// `total_logical_cores`.
+    pub fn available_parallelism(&self) -> Option<usize> {
+        self.total_logical_cores
+    }
+
+    /// Returns the logical cores the current process is allowed to run on.
+    ///
+    /// The affinity mask is only read on Linux, so this always returns an
+    /// empty vec on FreeBSD.
+    pub fn allowed_cores() -> Vec<u32> {
+        Vec::new()
+    }
+
+    /// Counts logical cores with `kern.smp.cpus`, falling back to `hw.ncpu`.
+    fn get_total_logical_cores_freebsd() -> Result<usize, CpuInfoError> {
+        Self::sysctl_u32("kern.smp.cpus")
+            .or_else(|_| Self::sysctl_u32("hw.ncpu"))
+            .map(|n| n as usize)
+    }
+
+    /// Detects the CPU manufacturer/vendor.
+    ///
+    /// On x86/x86_64, this uses CPUID like every other platform.
+    /// Other architectures don't expose a vendor through sysctl.
+    fn get_fabricant_freebsd() -> Result<Fabricant, CpuInfoError> {
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            Self::get_fabricant_cpuid()
+        }
+
+        #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+        {
+            Ok(Fabricant::Unknown)
+        }
+    }
+
+    /// Detects the CPU model name.
+    ///
+    /// On x86/x86_64, this uses CPUID for consistency with other platforms.
+    /// Falls back to `hw.model` otherwise.
+    fn get_model_freebsd() -> Result<String, CpuInfoError> {
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            if let Ok(model) = Self::get_model_cpuid() {
+                return Ok(model);
+            }
+        }
+
+        Self::sysctl_string("hw.model")
+    }
+
+    /// Detects instruction-set extensions.
+    ///
+    /// On x86/x86_64, this uses CPUID. Other architectures report no features yet.
+    fn get_features_freebsd() -> Vec<CpuFeature> {
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            Self::get_features_cpuid()
+        }
+
+        #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+        {
+            Vec::new()
+        }
+    }
+
+    /// Detects CPU core distribution from the per-core frequency sysctls.
+    ///
+    /// `dev.cpu.N.freq_levels` lists the supported frequencies fastest first
+    /// (`"3400/-1 3000/-1 ..."`), so its first entry is the maximum. Without
+    /// cpufreq support only `dev.cpu.N.freq` (the current frequency) may exist.
+    fn get_distribution_freebsd(total_logical_cores: Option<usize>) -> DistributionCore {
+        let count = total_logical_cores.unwrap_or(0) as u32;
+
+        let cores: Vec<Core> = (0..count)
+            .map(|id| {
+                let max_mhz = Self::sysctl_string(&format!("dev.cpu.{}.freq_levels", id))
+                    .ok()
+                    .and_then(|levels| {
+                        levels
+                            .split_whitespace()
+                            .next()?
+                            .split('/')
+                            .next()?
+                            .parse::<u32>()
+                            .ok()
+                    })
+                    .or_else(|| Self::sysctl_u32(&format!("dev.cpu.{}.freq", id)).ok())
+                    .unwrap_or(0);
+
+                let mut core = Core::new(id, max_mhz, None);
+                core.current_mhz = Self::sysctl_u32(&format!("dev.cpu.{}.freq", id)).unwrap_or(0);
+                core
+            })
+            .collect();
+
+        Self::detect_distribution(&cores)
+    }
+}
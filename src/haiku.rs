@@ -0,0 +1,359 @@
+use std::io;
+use crate::{Core,CpuFeature,CpuInfo,CpuInfoError,DistributionCore,Fabricant,IdleState,SmtControl,Vulnerability};
+
+#[cfg(feature = "haiku")]
+impl CpuInfo {
+    /// Creates a new `CpuInfo` instance by detecting all CPU information.
+    ///
+    /// On Haiku the counts and frequencies come from the kernel's
+    /// `get_system_info`, `get_cpu_info` and `get_cpu_topology_info` calls,
+    /// so no files or external commands are involved.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cpu_info::{CpuInfo, DistributionCore};
+    ///
+    /// let cpu_info = CpuInfo::new();
+    /// match cpu_info.distribution {
+    ///     DistributionCore::Lineal { mhz, .. } => {
+    ///         println!("Uniform CPU with all cores at {} MHz", mhz);
+    ///     }
+    ///     DistributionCore::Hybrid { ref groups } => {
+    ///         println!("Hybrid CPU with {} cores at different speeds", groups.len());
+    ///     }
+    /// }
+    /// ```
+    pub fn new() -> Self {
+        if let Some(Ok(info)) = Self::from_override() {
+            return info;
+        }
+
+        let cpus = Self::get_cpu_infos_haiku().unwrap_or_default();
+        let topology = Self::get_topology_haiku();
+        let count = |n: usize| (n > 0).then_some(n);
+
+        let total_logical_cores = count(cpus.len());
+        let total_physical_cores = count(Self::count_topology_haiku(&topology, libc::B_TOPOLOGY_CORE));
+
+        Self {
+            architecture: Self::get_architecture(),
+            endianness: Self::get_endianness(),
+            fabricant: Self::get_fabricant_haiku().unwrap_or(Fabricant::Unknown),
+            model: Self::get_model_haiku().unwrap_or("Unknown".to_string()),
+            total_logical_cores,
+            online_logical_cores: total_logical_cores
+                .map(|_| cpus.iter().filter(|cpu| cpu.enabled).count()),
+            total_physical_cores,
+            total_sockets: count(Self::count_topology_haiku(&topology, libc::B_TOPOLOGY_PACKAGE)),
+            smt_enabled: Self::smt_from_counts(total_logical_cores, total_physical_cores),
+            distribution: Self::detect_distribution(&Self::get_cores_haiku(&cpus, &topology)),
+            caches: Self::get_caches(),
+            features: Self::get_features_haiku(),
+            signature: Self::get_signature(),
+            microcode: None,
+            address_bits: Self::get_address_bits(),
+            hypervisor: Self::get_hypervisor(),
+            numa_nodes: Vec::new(),
+            clusters: Vec::new(),
+        }
+    }
+
+    /// Creates a new `CpuInfo` instance, reporting why detection failed.
+    ///
+    /// Unlike [`CpuInfo::new`], this returns [`CpuInfoError::Os`] when
+    /// `get_system_info` or `get_cpu_info` fails.
+    pub fn try_new() -> Result<Self, CpuInfoError> {
+        if let Some(result) = Self::from_override() {
+            return result;
+        }
+
+        let cpus = Self::get_cpu_infos_haiku()?;
+        let topology = Self::get_topology_haiku();
+        let count = |n: usize| (n > 0).then_some(n);
+
+        let total_logical_cores = Some(cpus.len());
+        let total_physical_cores = count(Self::count_topology_haiku(&topology, libc::B_TOPOLOGY_CORE));
+
+        Ok(Self {
+            architecture: Self::get_architecture(),
+            endianness: Self::get_endianness(),
+            fabricant: Self::get_fabricant_haiku()?,
+            model: Self::get_model_haiku()?,
+            total_logical_cores,
+            online_logical_cores: Some(cpus.iter().filter(|cpu| cpu.enabled).count()),
+            total_physical_cores,
+            total_sockets: count(Self::count_topology_haiku(&topology, libc::B_TOPOLOGY_PACKAGE)),
+            smt_enabled: Self::smt_from_counts(total_logical_cores, total_physical_cores),
+            distribution: Self::detect_distribution(&Self::get_cores_haiku(&cpus, &topology)),
+            caches: Self::get_caches(),
+            features: Self::get_features_haiku(),
+            signature: Self::get_signature(),
+            microcode: None,
+            address_bits: Self::get_address_bits(),
+            hypervisor: Self::get_hypervisor(),
+            numa_nodes: Vec::new(),
+            clusters: Vec::new(),
+        })
+    }
+
+    /// Re-reads the current frequency of every known core.
+    ///
+    /// Only `get_cpu_info` is queried again; every other field is left
+    /// untouched. It is a no-op for `DistributionCore::Lineal`, which doesn't
+    /// track current frequencies.
+    pub fn refresh_frequencies(&mut self) {
+        if let DistributionCore::Hybrid { groups } = &mut self.distribution {
+            let cpus = Self::get_cpu_infos_haiku().unwrap_or_default();
+            for core in groups {
+                core.current_mhz = cpus
+                    .get(core.id as usize)
+                    .map_or(0, |cpu| (cpu.current_frequency / 1_000_000) as u32);
+            }
+        }
+    }
+
+    /// Re-reads how many logical cores are online.
+    ///
+    /// Counts the CPUs `get_cpu_info` reports as enabled, which changes when
+    /// a CPU is switched off from the Processor preferences.
+    pub fn refresh_online(&mut self) {
+        self.online_logical_cores = Self::get_cpu_infos_haiku()
+            .ok()
+            .map(|cpus| cpus.iter().filter(|cpu| cpu.enabled).count());
+    }
+
+    /// Reads the current CPU package temperature in degrees Celsius.
+    ///
+    /// Haiku has no userland API for thermal sensors, so this always returns
+    /// `None`.
+    pub fn package_temperature_celsius() -> Option<f32> {
+        None
+    }
+
+    /// Reads the configured package power limit in watts.
+    ///
+    /// Haiku has no userland API for power limits, so this always returns
+    /// `None`.
+    pub fn power_limit_watts() -> Option<f64> {
+        None
+    }
+
+    /// Returns `true` if every core uses the `performance` cpufreq governor.
+    ///
+    /// cpufreq governors are Linux-only, so this always returns `false` on
+    /// Haiku.
+    pub fn all_cores_performance() -> bool {
+        false
+    }
+
+    /// Returns `true` if any core exposes a Linux `cpufreq` directory.
+    ///
+    /// Always `false` on Haiku.
+    pub fn has_cpufreq() -> bool {
+        false
+    }
+
+    /// Returns the active cpufreq scaling driver.
+    ///
+    /// Always `None` on Haiku, which has no cpufreq drivers.
+    pub fn scaling_driver() -> Option<String> {
+        None
+    }
+
+    /// Reads whether SMT (Hyper-Threading) can be switched at runtime.
+    ///
+    /// Only read from Linux's `smt/control`; always `None` on Haiku.
+    pub fn smt_control() -> Option<SmtControl> {
+        None
+    }
+
+    /// Returns whether turbo boost is currently enabled.
+    ///
+    /// Only read from Linux's `intel_pstate` and `cpufreq` controls; always
+    /// `None` on Haiku.
+    pub fn turbo_enabled() -> Option<bool> {
+        None
+    }
+
+    /// Returns the logical cores isolated from the scheduler with `isolcpus`.
+    ///
+    /// `isolcpus` is a Linux boot option, so this always returns an empty vec
+    /// on Haiku.
+    pub fn isolated_cores() -> Vec<u32> {
+        Vec::new()
+    }
+
+    /// Returns the groups of logical cores sharing a cache at `level`.
+    ///
+    /// Sharing lists are only read from Linux sysfs, so this always returns
+    /// an empty vec on Haiku.
+    pub fn cores_sharing_cache(_level: u8) -> Vec<Vec<u32>> {
+        Vec::new()
+    }
+
+    /// Reads the kernel's status for every known CPU vulnerability.
+    ///
+    /// Only read from Linux's `vulnerabilities/` directory, so this always
+    /// returns an empty vec on Haiku.
+    pub fn vulnerabilities() -> Vec<Vulnerability> {
+        Vec::new()
+    }
+
+    /// Reads the idle states (C-states) the kernel can put cpu0 into.
+    ///
+    /// Only read from Linux's `cpuidle`, so this always returns an empty vec
+    /// on Haiku.
+    pub fn idle_states() -> Vec<IdleState> {
+        Vec::new()
+    }
+
+    /// Returns the number of CPUs this process can effectively use.
+    ///
+    /// Cgroup CPU quotas are Linux-only, so on Haiku this is
+    /// `total_logical_cores`.
+    pub fn available_parallelism(&self) -> Option<usize> {
+        self.total_logical_cores
+    }
+
+    /// Returns the logical cores the current process is allowed to run on.
+    ///
+    /// The affinity mask is only read on Linux, so this always returns an
+    /// empty vec on Haiku.
+    pub fn allowed_cores() -> Vec<u32> {
+        Vec::new()
+    }
+
+    /// Reads one `cpu_info` per logical CPU, sized from `system_info.cpu_count`.
+    fn get_cpu_infos_haiku() -> Result<Vec<libc::cpu_info>, CpuInfoError> {
+        let mut system: libc::system_info = unsafe { std::mem::zeroed() };
+        let status = unsafe { libc::get_system_info(&mut system) };
+        if status != libc::B_OK {
+            return Err(CpuInfoError::Os(io::Error::from_raw_os_error(status)));
+        }
+
+        let mut cpus: Vec<libc::cpu_info> = vec![unsafe { std::mem::zeroed() }; system.cpu_count as usize];
+        let status = unsafe { libc::get_cpu_info(0, system.cpu_count, cpus.as_mut_ptr()) };
+        if status != libc::B_OK {
+            return Err(CpuInfoError::Os(io::Error::from_raw_os_error(status)));
+        }
+
+        Ok(cpus)
+    }
+
+    /// Reads the CPU topology tree, flattened in pre-order
+    /// (root, package, core, SMT threads, next core, ...).
+    ///
+    /// Returns an empty vec if the kernel doesn't report it.
+    fn get_topology_haiku() -> Vec<libc::cpu_topology_node_info> {
+        let mut count: u32 = 0;
+        // First call only queries the node count
+        let status = unsafe { libc::get_cpu_topology_info(std::ptr::null_mut(), &mut count) };
+        if status != libc::B_OK || count == 0 {
+            return Vec::new();
+        }
+
+        let mut nodes: Vec<libc::cpu_topology_node_info> = vec![unsafe { std::mem::zeroed() }; count as usize];
+        // The kernel updates `count` to the number of nodes it wrote
+        let status = unsafe { libc::get_cpu_topology_info(nodes.as_mut_ptr(), &mut count) };
+        if status != libc::B_OK {
+            return Vec::new();
+        }
+
+        nodes.truncate(count as usize);
+        nodes
+    }
+
+    /// Counts the topology nodes of one level, e.g. `B_TOPOLOGY_CORE`.
+    fn count_topology_haiku(topology: &[libc::cpu_topology_node_info], level: libc::topology_level_type) -> usize {
+        topology.iter().filter(|node| node.type_ == level).count()
+    }
+
+    /// Builds one `Core` per logical CPU.
+    ///
+    /// Each SMT node of the topology is a logical CPU and follows the core
+    /// node it belongs to, whose `default_frequency` (the nominal clock, in
+    /// Hz) is used as the speed. Without topology data only the current
+    /// frequency from `get_cpu_info` is known, and it is used for both.
+    fn get_cores_haiku(cpus: &[libc::cpu_info], topology: &[libc::cpu_topology_node_info]) -> Vec<Core> {
+        let current_mhz = |id: u32| {
+            cpus.get(id as usize)
+                .map_or(0, |cpu| (cpu.current_frequency / 1_000_000) as u32)
+        };
+
+        let mut cores = Vec::new();
+        let mut physical_core_id = None;
+        let mut default_mhz = 0;
+        for node in topology {
+            if node.type_ == libc::B_TOPOLOGY_CORE {
+                physical_core_id = Some(physical_core_id.map_or(0, |id: u32| id + 1));
+                // The union holds the `core` member for core nodes
+                default_mhz = (unsafe { node.data.core.default_frequency } / 1_000_000) as u32;
+            } else if node.type_ == libc::B_TOPOLOGY_SMT {
+                let mut core = Core::new(node.id, default_mhz, physical_core_id);
+                core.base_mhz = default_mhz;
+                core.current_mhz = current_mhz(node.id);
+                cores.push(core);
+            }
+        }
+
+        if cores.is_empty() {
+            cores = (0..cpus.len() as u32)
+                .map(|id| {
+                    let mut core = Core::new(id, current_mhz(id), None);
+                    core.current_mhz = current_mhz(id);
+                    core
+                })
+                .collect();
+        }
+
+        cores
+    }
+
+    /// Detects the CPU manufacturer/vendor.
+    ///
+    /// On x86/x86_64, this uses CPUID like every other platform.
+    /// Other architectures don't expose a vendor.
+    fn get_fabricant_haiku() -> Result<Fabricant, CpuInfoError> {
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            Self::get_fabricant_cpuid()
+        }
+
+        #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+        {
+            Ok(Fabricant::Unknown)
+        }
+    }
+
+    /// Detects the CPU model name.
+    ///
+    /// On x86/x86_64, this uses CPUID. Other architectures don't expose a
+    /// model name and report `"Unknown"`.
+    fn get_model_haiku() -> Result<String, CpuInfoError> {
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            Self::get_model_cpuid()
+        }
+
+        #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+        {
+            Ok("Unknown".to_string())
+        }
+    }
+
+    /// Detects instruction-set extensions.
+    ///
+    /// On x86/x86_64, this uses CPUID. Other architectures report no features yet.
+    fn get_features_haiku() -> Vec<CpuFeature> {
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            Self::get_features_cpuid()
+        }
+
+        #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+        {
+            Vec::new()
+        }
+    }
+}
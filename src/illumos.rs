@@ -0,0 +1,477 @@
+use std::collections::HashSet;
+use std::ffi::{CStr, c_char, c_int, c_void};
+use std::io;
+use crate::{Core,CpuFeature,CpuInfo,CpuInfoError,DistributionCore,Fabricant,IdleState,SmtControl,Vulnerability};
+
+// Minimal bindings to libkstat(3LIB); only the calls used below are declared.
+#[repr(C)]
+struct KstatCtl {
+    _private: [u8; 0],
+}
+
+#[repr(C)]
+struct Kstat {
+    _private: [u8; 0],
+}
+
+/// `kstat_named_t` from <sys/kstat.h>.
+#[repr(C)]
+struct KstatNamed {
+    name: [c_char; 31],
+    data_type: u8,
+    value: KstatValue,
+}
+
+#[repr(C)]
+union KstatValue {
+    c: [u8; 16],
+    i32: i32,
+    ui32: u32,
+    i64: i64,
+    ui64: u64,
+    str: KstatStr,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct KstatStr {
+    addr: *const c_char,
+    len: u32,
+}
+
+const KSTAT_DATA_CHAR: u8 = 0;
+const KSTAT_DATA_INT32: u8 = 1;
+const KSTAT_DATA_UINT32: u8 = 2;
+const KSTAT_DATA_INT64: u8 = 3;
+const KSTAT_DATA_UINT64: u8 = 4;
+const KSTAT_DATA_STRING: u8 = 9;
+
+#[link(name = "kstat")]
+unsafe extern "C" {
+    fn kstat_open() -> *mut KstatCtl;
+    fn kstat_close(kc: *mut KstatCtl) -> c_int;
+    fn kstat_lookup(kc: *mut KstatCtl, module: *const c_char, instance: c_int, name: *const c_char) -> *mut Kstat;
+    fn kstat_read(kc: *mut KstatCtl, ksp: *mut Kstat, buf: *mut c_void) -> c_int;
+    fn kstat_data_lookup(ksp: *mut Kstat, name: *const c_char) -> *mut c_void;
+}
+
+/// An open kstat chain, closed on drop.
+struct KstatHandle(*mut KstatCtl);
+
+impl KstatHandle {
+    fn open() -> Result<Self, CpuInfoError> {
+        let kc = unsafe { kstat_open() };
+        if kc.is_null() {
+            Err(CpuInfoError::Os(io::Error::last_os_error()))
+        } else {
+            Ok(Self(kc))
+        }
+    }
+
+    /// Reads the `cpu_info:<instance>` kstat, or `None` if that CPU doesn't exist.
+    fn cpu_info(&self, instance: u32) -> Option<CpuKstat<'_>> {
+        let ksp = unsafe { kstat_lookup(self.0, c"cpu_info".as_ptr(), instance as c_int, std::ptr::null()) };
+        if ksp.is_null() || unsafe { kstat_read(self.0, ksp, std::ptr::null_mut()) } == -1 {
+            return None;
+        }
+        Some(CpuKstat { ksp, _handle: self })
+    }
+}
+
+impl Drop for KstatHandle {
+    fn drop(&mut self) {
+        unsafe { kstat_close(self.0) };
+    }
+}
+
+/// A `cpu_info` kstat whose data has been read.
+struct CpuKstat<'a> {
+    ksp: *mut Kstat,
+    _handle: &'a KstatHandle,
+}
+
+impl CpuKstat<'_> {
+    fn named(&self, name: &CStr) -> Option<&KstatNamed> {
+        let named = unsafe { kstat_data_lookup(self.ksp, name.as_ptr()) } as *const KstatNamed;
+        unsafe { named.as_ref() }
+    }
+
+    /// Reads an integer statistic of any width.
+    fn u64(&self, name: &CStr) -> Option<u64> {
+        let named = self.named(name)?;
+        unsafe {
+            match named.data_type {
+                KSTAT_DATA_INT32 => u64::try_from(named.value.i32).ok(),
+                KSTAT_DATA_UINT32 => Some(u64::from(named.value.ui32)),
+                KSTAT_DATA_INT64 => u64::try_from(named.value.i64).ok(),
+                KSTAT_DATA_UINT64 => Some(named.value.ui64),
+                _ => None,
+            }
+        }
+    }
+
+    /// Reads a string statistic, stored either inline or out of line.
+    fn string(&self, name: &CStr) -> Option<String> {
+        let named = self.named(name)?;
+        let value = unsafe {
+            match named.data_type {
+                KSTAT_DATA_CHAR => {
+                    let bytes = &named.value.c;
+                    let end = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+                    String::from_utf8_lossy(&bytes[..end]).into_owned()
+                }
+                KSTAT_DATA_STRING if !named.value.str.addr.is_null() => {
+                    CStr::from_ptr(named.value.str.addr).to_string_lossy().into_owned()
+                }
+                _ => return None,
+            }
+        };
+
+        let value = value.trim();
+        (!value.is_empty()).then(|| value.to_string())
+    }
+
+    /// Reads the current clock from `current_clock_Hz`, in MHz.
+    fn current_mhz(&self) -> Option<u32> {
+        self.u64(c"current_clock_Hz").map(|hz| (hz / 1_000_000) as u32)
+    }
+
+    /// Reads the maximum clock in MHz.
+    ///
+    /// `supported_frequencies_Hz` lists every P-state (`"800000000:...:3400000000"`);
+    /// without a frequency driver only the nominal `clock_MHz` is available.
+    fn max_mhz(&self) -> Option<u32> {
+        self.string(c"supported_frequencies_Hz")
+            .and_then(|levels| {
+                levels
+                    .split(':')
+                    .filter_map(|level| level.parse::<u64>().ok())
+                    .max()
+            })
+            .map(|hz| (hz / 1_000_000) as u32)
+            .or_else(|| self.u64(c"clock_MHz").map(|mhz| mhz as u32))
+    }
+}
+
+#[cfg(feature = "illumos")]
+impl CpuInfo {
+    /// Creates a new `CpuInfo` instance by detecting all CPU information.
+    ///
+    /// On illumos and Solaris the per-CPU values come from the `cpu_info`
+    /// kstats and the core count from `sysconf`, so no files or external
+    /// commands are involved.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cpu_info::{CpuInfo, DistributionCore};
+    ///
+    /// let cpu_info = CpuInfo::new();
+    /// match cpu_info.distribution {
+    ///     DistributionCore::Lineal { mhz, .. } => {
+    ///         println!("Uniform CPU with all cores at {} MHz", mhz);
+    ///     }
+    ///     DistributionCore::Hybrid { ref groups } => {
+    ///         println!("Hybrid CPU with {} cores at different speeds", groups.len());
+    ///     }
+    /// }
+    /// ```
+    pub fn new() -> Self {
+        if let Some(Ok(info)) = Self::from_override() {
+            return info;
+        }
+
+        let total_logical_cores = Self::get_total_logical_cores_illumos().ok();
+        let kstat = KstatHandle::open().ok();
+        let cores = kstat.as_ref().map(Self::get_cores_illumos).unwrap_or_default();
+        let (total_physical_cores, total_sockets) = kstat
+            .as_ref()
+            .map_or((None, None), Self::get_topology_illumos);
+
+        Self {
+            architecture: Self::get_architecture(),
+            endianness: Self::get_endianness(),
+            fabricant: Self::get_fabricant_illumos().unwrap_or(Fabricant::Unknown),
+            model: Self::get_model_illumos(kstat.as_ref()).unwrap_or("Unknown".to_string()),
+            total_logical_cores,
+            online_logical_cores: total_logical_cores,
+            total_physical_cores,
+            total_sockets,
+            smt_enabled: Self::smt_from_counts(total_logical_cores, total_physical_cores),
+            distribution: Self::detect_distribution(&cores),
+            caches: Self::get_caches(),
+            features: Self::get_features_illumos(),
+            signature: Self::get_signature(),
+            microcode: None,
+            address_bits: Self::get_address_bits(),
+            hypervisor: Self::get_hypervisor(),
+            numa_nodes: Vec::new(),
+            clusters: Vec::new(),
+        }
+    }
+
+    /// Creates a new `CpuInfo` instance, reporting why detection failed.
+    ///
+    /// Unlike [`CpuInfo::new`], this returns [`CpuInfoError::Os`] when the
+    /// kstat chain cannot be opened or `sysconf` fails.
+    pub fn try_new() -> Result<Self, CpuInfoError> {
+        if let Some(result) = Self::from_override() {
+            return result;
+        }
+
+        let total_logical_cores = Some(Self::get_total_logical_cores_illumos()?);
+        let kstat = KstatHandle::open()?;
+        let cores = Self::get_cores_illumos(&kstat);
+        let (total_physical_cores, total_sockets) = Self::get_topology_illumos(&kstat);
+
+        Ok(Self {
+            architecture: Self::get_architecture(),
+            endianness: Self::get_endianness(),
+            fabricant: Self::get_fabricant_illumos()?,
+            model: Self::get_model_illumos(Some(&kstat))?,
+            total_logical_cores,
+            online_logical_cores: total_logical_cores,
+            total_physical_cores,
+            total_sockets,
+            smt_enabled: Self::smt_from_counts(total_logical_cores, total_physical_cores),
+            distribution: Self::detect_distribution(&cores),
+            caches: Self::get_caches(),
+            features: Self::get_features_illumos(),
+            signature: Self::get_signature(),
+            microcode: None,
+            address_bits: Self::get_address_bits(),
+            hypervisor: Self::get_hypervisor(),
+            numa_nodes: Vec::new(),
+            clusters: Vec::new(),
+        })
+    }
+
+    /// Re-reads the current frequency of every known core.
+    ///
+    /// Only `current_clock_Hz` is read again; every other field is left
+    /// untouched. It is a no-op for `DistributionCore::Lineal`, which doesn't
+    /// track current frequencies.
+    pub fn refresh_frequencies(&mut self) {
+        let Ok(kstat) = KstatHandle::open() else {
+            return;
+        };
+
+        if let DistributionCore::Hybrid { groups } = &mut self.distribution {
+            for core in groups {
+                core.current_mhz = kstat
+                    .cpu_info(core.id)
+                    .and_then(|cpu| cpu.current_mhz())
+                    .unwrap_or(0);
+            }
+        }
+    }
+
+    /// Re-reads how many logical cores are online.
+    ///
+    /// `online_logical_cores` mirrors the total on illumos, so this is a no-op.
+    pub fn refresh_online(&mut self) {}
+
+    /// Reads the current CPU package temperature in degrees Celsius.
+    ///
+    /// illumos exposes temperatures through the topology framework rather
+    /// than kstats, so this always returns `None`.
+    pub fn package_temperature_celsius() -> Option<f32> {
+        None
+    }
+
+    /// Reads the configured package power limit in watts.
+    ///
+    /// illumos exposes no package power limit through kstats, so this always
+    /// returns `None`.
+    pub fn power_limit_watts() -> Option<f64> {
+        None
+    }
+
+    /// Returns `true` if every core uses the `performance` cpufreq governor.
+    ///
+    /// cpufreq governors are Linux-only, so this always returns `false` on
+    /// illumos.
+    pub fn all_cores_performance() -> bool {
+        false
+    }
+
+    /// Returns `true` if any core exposes a Linux `cpufreq` directory.
+    ///
+    /// Always `false` on illumos.
+    pub fn has_cpufreq() -> bool {
+        false
+    }
+
+    /// Returns the active cpufreq scaling driver.
+    ///
+    /// Always `None` on illumos, which has no cpufreq drivers.
+    pub fn scaling_driver() -> Option<String> {
+        None
+    }
+
+    /// Reads whether SMT (Hyper-Threading) can be switched at runtime.
+    ///
+    /// Only read from Linux's `smt/control`; always `None` on illumos.
+    pub fn smt_control() -> Option<SmtControl> {
+        None
+    }
+
+    /// Returns whether turbo boost is currently enabled.
+    ///
+    /// Only read from Linux's `intel_pstate` and `cpufreq` controls; always
+    /// `None` on illumos.
+    pub fn turbo_enabled() -> Option<bool> {
+        None
+    }
+
+    /// Returns the logical cores isolated from the scheduler with `isolcpus`.
+    ///
+    /// `isolcpus` is a Linux boot option, so this always returns an empty vec
+    /// on illumos.
+    pub fn isolated_cores() -> Vec<u32> {
+        Vec::new()
+    }
+
+    /// Returns the groups of logical cores sharing a cache at `level`.
+    ///
+    /// Sharing lists are only read from Linux sysfs, so this always returns
+    /// an empty vec on illumos.
+    pub fn cores_sharing_cache(_level: u8) -> Vec<Vec<u32>> {
+        Vec::new()
+    }
+
+    /// Reads the kernel's status for every known CPU vulnerability.
+    ///
+    /// Only read from Linux's `vulnerabilities/` directory, so this always
+    /// returns an empty vec on illumos.
+    pub fn vulnerabilities() -> Vec<Vulnerability> {
+        Vec::new()
+    }
+
+    /// Reads the idle states (C-states) the kernel can put cpu0 into.
+    ///
+    /// Only read from Linux's `cpuidle`, so this always returns an empty vec
+    /// on illumos.
+    pub fn idle_states() -> Vec<IdleState> {
+        Vec::new()
+    }
+
+    /// Returns the number of CPUs this process can effectively use.
+    ///
+    /// Cgroup CPU quotas are Linux-only, so on illumos this is
+    /// `total_logical_cores`.
+    pub fn available_parallelism(&self) -> Option<usize> {
+        self.total_logical_cores
+    }
+
+    /// Returns the logical cores the current process is allowed to run on.
+    ///
+    /// The affinity mask is only read on Linux, so this always returns an
+    /// empty vec on illumos.
+    pub fn allowed_cores() -> Vec<u32> {
+        Vec::new()
+    }
+
+    /// Counts online logical cores with `sysconf(_SC_NPROCESSORS_ONLN)`.
+    fn get_total_logical_cores_illumos() -> Result<usize, CpuInfoError> {
+        let count = unsafe { libc::sysconf(libc::_SC_NPROCESSORS_ONLN) };
+        if count < 1 {
+            Err(CpuInfoError::Os(io::Error::last_os_error()))
+        } else {
+            Ok(count as usize)
+        }
+    }
+
+    /// Number of `cpu_info` instances to probe, including offline CPUs.
+    fn get_configured_cpus_illumos() -> u32 {
+        let count = unsafe { libc::sysconf(libc::_SC_NPROCESSORS_CONF) };
+        count.max(0) as u32
+    }
+
+    /// Detects the CPU manufacturer/vendor.
+    ///
+    /// On x86/x86_64, this uses CPUID like every other platform.
+    /// Other architectures don't expose a vendor the crate can map.
+    fn get_fabricant_illumos() -> Result<Fabricant, CpuInfoError> {
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            Self::get_fabricant_cpuid()
+        }
+
+        #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+        {
+            Ok(Fabricant::Unknown)
+        }
+    }
+
+    /// Detects the CPU model name.
+    ///
+    /// On x86/x86_64, this uses CPUID for consistency with other platforms.
+    /// Falls back to the `brand` statistic of the first CPU otherwise.
+    fn get_model_illumos(kstat: Option<&KstatHandle>) -> Result<String, CpuInfoError> {
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            if let Ok(model) = Self::get_model_cpuid() {
+                return Ok(model);
+            }
+        }
+
+        kstat
+            .and_then(|kstat| {
+                (0..Self::get_configured_cpus_illumos())
+                    .find_map(|id| kstat.cpu_info(id)?.string(c"brand"))
+            })
+            .ok_or_else(|| CpuInfoError::ParseError("No brand in cpu_info kstat".to_string()))
+    }
+
+    /// Detects instruction-set extensions.
+    ///
+    /// On x86/x86_64, this uses CPUID. Other architectures report no features yet.
+    fn get_features_illumos() -> Vec<CpuFeature> {
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            Self::get_features_cpuid()
+        }
+
+        #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+        {
+            Vec::new()
+        }
+    }
+
+    /// Reads the frequency and physical core of every CPU from its `cpu_info` kstat.
+    fn get_cores_illumos(kstat: &KstatHandle) -> Vec<Core> {
+        (0..Self::get_configured_cpus_illumos())
+            .filter_map(|id| {
+                let cpu = kstat.cpu_info(id)?;
+                let physical_core_id = cpu.u64(c"core_id").map(|core_id| core_id as u32);
+
+                let mut core = Core::new(id, cpu.max_mhz().unwrap_or(0), physical_core_id);
+                core.current_mhz = cpu.current_mhz().unwrap_or(0);
+                Some(core)
+            })
+            .collect()
+    }
+
+    /// Counts physical cores and sockets from the `chip_id`/`core_id` statistics.
+    ///
+    /// `core_id` is only unique within a chip, so cores are keyed by both.
+    fn get_topology_illumos(kstat: &KstatHandle) -> (Option<usize>, Option<usize>) {
+        let mut cores = HashSet::new();
+        let mut chips = HashSet::new();
+
+        for id in 0..Self::get_configured_cpus_illumos() {
+            let Some(cpu) = kstat.cpu_info(id) else {
+                continue;
+            };
+            let chip_id = cpu.u64(c"chip_id");
+            chips.extend(chip_id);
+            if let Some(core_id) = cpu.u64(c"core_id") {
+                cores.insert((chip_id, core_id));
+            }
+        }
+
+        let count = |n: usize| (n > 0).then_some(n);
+        (count(cores.len()), count(chips.len()))
+    }
+}
@@ -0,0 +1,240 @@
+use alloc::string::String;
+use core::fmt::{self, Debug, Write};
+use crate::{CacheInfo,Core,CpuCluster,CpuInfo,DistributionCore,Fabricant,Hypervisor,NumaNode};
+
+impl CpuInfo {
+    /// Serializes all fields as a compact JSON document.
+    ///
+    /// The document has exactly the shape produced by the `serde` feature, so
+    /// the two can be used interchangeably:
+    ///
+    /// - keys are the Rust field names, in declaration order, after a leading
+    ///   `schema_version` ([`CpuInfo::SCHEMA_VERSION`])
+    /// - `None` is `null`, lists are arrays
+    /// - fieldless variants are strings (`"X86_64"`, `"Avx2"`, `"Performance"`)
+    /// - variants with data are single-key objects, e.g. `{"Other":"Cavium"}`
+    ///   or `{"Lineal":{"mhz":4900,"base_mhz":3600}}`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cpu_info::{Core, CpuArchitecture, CpuInfo, Fabricant};
+    ///
+    /// let info = CpuInfo::builder()
+    ///     .model("Test \"Hybrid\" CPU")
+    ///     .architecture(CpuArchitecture::X86_64)
+    ///     .fabricant(Fabricant::Intel)
+    ///     .add_core(Core::new(0, 5000, Some(0)))
+    ///     .add_core(Core::new(1, 3800, Some(1)))
+    ///     .build();
+    ///
+    /// assert_eq!(
+    ///     info.to_json(),
+    ///     concat!(
+    ///         r#"{"schema_version":1,"architecture":"X86_64","endianness":"Little","fabricant":"Intel","model":"Test \"Hybrid\" CPU","#,
+    ///         r#""total_logical_cores":2,"online_logical_cores":2,"total_physical_cores":2,"#,
+    ///         r#""total_sockets":null,"smt_enabled":false,"#,
+    ///         r#""distribution":{"Hybrid":{"groups":["#,
+    ///         r#"{"id":0,"speed_mhz":5000,"current_mhz":0,"base_mhz":0,"min_mhz":0,"scaling_max_mhz":0,"hardware_max_mhz":0,"physical_core_id":0,"core_type":null,"capacity":null,"governor":null,"part_name":null,"siblings":[]},"#,
+    ///         r#"{"id":1,"speed_mhz":3800,"current_mhz":0,"base_mhz":0,"min_mhz":0,"scaling_max_mhz":0,"hardware_max_mhz":0,"physical_core_id":1,"core_type":null,"capacity":null,"governor":null,"part_name":null,"siblings":[]}"#,
+    ///         r#"]}},"caches":[],"features":[],"signature":null,"microcode":null,"address_bits":null,"numa_nodes":[],"clusters":[],"hypervisor":null}"#,
+    ///     )
+    /// );
+    ///
+    /// # #[cfg(feature = "serde")]
+    /// assert_eq!(info.to_json(), serde_json::to_string(&info).unwrap());
+    /// ```
+    pub fn to_json(&self) -> String {
+        let mut out = String::new();
+        // Writing into a String never fails
+        let _ = self.write_json(&mut out);
+        out
+    }
+
+    fn write_json(&self, out: &mut String) -> fmt::Result {
+        write!(out, "{{\"schema_version\":{}", Self::SCHEMA_VERSION)?;
+        write!(out, ",\"architecture\":\"{:?}\"", self.architecture)?;
+        write!(out, ",\"endianness\":\"{:?}\"", self.endianness)?;
+        out.push_str(",\"fabricant\":");
+        match &self.fabricant {
+            Fabricant::Other(vendor) => write_variant_str(out, "Other", vendor)?,
+            fabricant => write!(out, "\"{:?}\"", fabricant)?,
+        }
+        out.push_str(",\"model\":");
+        write_str(out, &self.model)?;
+        out.push_str(",\"total_logical_cores\":");
+        write_option(out, self.total_logical_cores)?;
+        out.push_str(",\"online_logical_cores\":");
+        write_option(out, self.online_logical_cores)?;
+        out.push_str(",\"total_physical_cores\":");
+        write_option(out, self.total_physical_cores)?;
+        out.push_str(",\"total_sockets\":");
+        write_option(out, self.total_sockets)?;
+        out.push_str(",\"smt_enabled\":");
+        write_option(out, self.smt_enabled)?;
+
+        out.push_str(",\"distribution\":");
+        match &self.distribution {
+            DistributionCore::Lineal { mhz, base_mhz } => {
+                write!(out, "{{\"Lineal\":{{\"mhz\":{},\"base_mhz\":{}}}}}", mhz, base_mhz)?;
+            }
+            DistributionCore::Hybrid { groups } => {
+                out.push_str("{\"Hybrid\":{\"groups\":");
+                write_list(out, groups, write_core)?;
+                out.push_str("}}");
+            }
+        }
+
+        out.push_str(",\"caches\":");
+        write_list(out, &self.caches, write_cache)?;
+        out.push_str(",\"features\":");
+        write_list(out, &self.features, |out, feature| write!(out, "\"{:?}\"", feature))?;
+
+        out.push_str(",\"signature\":");
+        match &self.signature {
+            Some(sig) => write!(
+                out,
+                "{{\"family\":{},\"model\":{},\"stepping\":{}}}",
+                sig.family, sig.model, sig.stepping
+            )?,
+            None => out.push_str("null"),
+        }
+
+        out.push_str(",\"microcode\":");
+        write_option(out, self.microcode)?;
+        out.push_str(",\"address_bits\":");
+        match &self.address_bits {
+            Some(bits) => write!(
+                out,
+                "{{\"physical\":{},\"virtual_\":{}}}",
+                bits.physical, bits.virtual_
+            )?,
+            None => out.push_str("null"),
+        }
+        out.push_str(",\"numa_nodes\":");
+        write_list(out, &self.numa_nodes, write_numa_node)?;
+        out.push_str(",\"clusters\":");
+        write_list(out, &self.clusters, write_cluster)?;
+
+        out.push_str(",\"hypervisor\":");
+        match &self.hypervisor {
+            Some(Hypervisor::Other(vendor)) => write_variant_str(out, "Other", vendor)?,
+            Some(hypervisor) => write!(out, "\"{:?}\"", hypervisor)?,
+            None => out.push_str("null"),
+        }
+
+        out.push('}');
+        Ok(())
+    }
+}
+
+fn write_core(out: &mut String, core: &Core) -> fmt::Result {
+    write!(
+        out,
+        "{{\"id\":{},\"speed_mhz\":{},\"current_mhz\":{},\"base_mhz\":{},\"min_mhz\":{},\"scaling_max_mhz\":{},\"hardware_max_mhz\":{},\"physical_core_id\":",
+        core.id, core.speed_mhz, core.current_mhz, core.base_mhz, core.min_mhz,
+        core.scaling_max_mhz, core.hardware_max_mhz
+    )?;
+    write_option(out, core.physical_core_id)?;
+    out.push_str(",\"core_type\":");
+    match core.core_type {
+        Some(core_type) => write!(out, "\"{:?}\"", core_type)?,
+        None => out.push_str("null"),
+    }
+    out.push_str(",\"capacity\":");
+    write_option(out, core.capacity)?;
+    out.push_str(",\"governor\":");
+    match &core.governor {
+        Some(governor) => write_str(out, governor)?,
+        None => out.push_str("null"),
+    }
+    out.push_str(",\"part_name\":");
+    match &core.part_name {
+        Some(part_name) => write_str(out, part_name)?,
+        None => out.push_str("null"),
+    }
+    out.push_str(",\"siblings\":");
+    write_list(out, &core.siblings, |out, cpu| write!(out, "{}", cpu))?;
+    out.push('}');
+    Ok(())
+}
+
+fn write_cache(out: &mut String, cache: &CacheInfo) -> fmt::Result {
+    write!(
+        out,
+        "{{\"level\":{},\"kind\":\"{:?}\",\"size_bytes\":{},\"line_size\":{},\"shared_by\":{},\"shared_cpu_ids\":",
+        cache.level, cache.kind, cache.size_bytes, cache.line_size, cache.shared_by
+    )?;
+    write_list(out, &cache.shared_cpu_ids, |out, cpu| write!(out, "{}", cpu))?;
+    out.push('}');
+    Ok(())
+}
+
+fn write_numa_node(out: &mut String, node: &NumaNode) -> fmt::Result {
+    write!(out, "{{\"id\":{},\"cpus\":", node.id)?;
+    write_list(out, &node.cpus, |out, cpu| write!(out, "{}", cpu))?;
+    out.push('}');
+    Ok(())
+}
+
+fn write_cluster(out: &mut String, cluster: &CpuCluster) -> fmt::Result {
+    write!(out, "{{\"id\":{},\"cpus\":", cluster.id)?;
+    write_list(out, &cluster.cpus, |out, cpu| write!(out, "{}", cpu))?;
+    write!(out, ",\"max_mhz\":{}}}", cluster.max_mhz)
+}
+
+/// Writes `[a,b,...]` using `write_item` for every element.
+fn write_list<T>(
+    out: &mut String,
+    items: &[T],
+    write_item: impl Fn(&mut String, &T) -> fmt::Result,
+) -> fmt::Result {
+    out.push('[');
+    for (i, item) in items.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        write_item(out, item)?;
+    }
+    out.push(']');
+    Ok(())
+}
+
+/// Writes a number or boolean, or `null` for `None`.
+fn write_option<T: Debug>(out: &mut String, value: Option<T>) -> fmt::Result {
+    match value {
+        Some(value) => write!(out, "{:?}", value),
+        None => {
+            out.push_str("null");
+            Ok(())
+        }
+    }
+}
+
+/// Writes `{"<variant>":"<value>"}` for newtype variants holding a string.
+fn write_variant_str(out: &mut String, variant: &str, value: &str) -> fmt::Result {
+    write!(out, "{{\"{}\":", variant)?;
+    write_str(out, value)?;
+    out.push('}');
+    Ok(())
+}
+
+/// Writes a quoted JSON string, escaping quotes, backslashes and control characters.
+fn write_str(out: &mut String, value: &str) -> fmt::Result {
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\u{8}' => out.push_str("\\b"),
+            '\u{c}' => out.push_str("\\f"),
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32)?,
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    Ok(())
+}
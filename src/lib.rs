@@ -1,11 +1,71 @@
-#[cfg(feature = "linux")]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(all(feature = "linux", any(target_os = "linux", target_os = "android")))]
 pub mod linux;
 
 
-#[cfg(feature = "windows")]
+#[cfg(all(feature = "windows", target_os = "windows"))]
 pub mod windows;
 
 
+#[cfg(all(feature = "macos", target_os = "macos"))]
+pub mod macos;
+
+
+#[cfg(all(feature = "freebsd", target_os = "freebsd"))]
+pub mod freebsd;
+
+#[cfg(all(feature = "openbsd", target_os = "openbsd"))]
+pub mod openbsd;
+
+#[cfg(all(feature = "netbsd", target_os = "netbsd"))]
+pub mod netbsd;
+
+#[cfg(all(feature = "illumos", any(target_os = "illumos", target_os = "solaris")))]
+pub mod illumos;
+
+#[cfg(all(feature = "haiku", target_os = "haiku"))]
+pub mod haiku;
+
+#[cfg(all(feature = "std", target_family = "wasm"))]
+pub mod wasm;
+
+#[cfg(any(
+    all(feature = "macos", target_os = "macos"),
+    all(feature = "freebsd", target_os = "freebsd"),
+    all(feature = "openbsd", target_os = "openbsd"),
+    all(feature = "netbsd", target_os = "netbsd"),
+))]
+mod sysctl;
+
+mod common;
+
+mod procfs;
+
+mod lscpu;
+
+mod errata;
+
+#[cfg(feature = "json")]
+mod json;
+
+#[cfg(all(feature = "std", any(target_arch = "x86", target_arch = "x86_64")))]
+mod cpuid;
+
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt::{self, Write};
+use core::hash::{Hash, Hasher};
+use core::num::NonZeroUsize;
+#[cfg(feature = "std")]
+use std::io;
+
+
 
 /// Comprehensive CPU information structure.
 ///
@@ -15,35 +75,1129 @@ pub mod windows;
 /// # Examples
 ///
 /// ```no_run
-/// use your_crate::CpuInfo;
+/// use cpu_info::CpuInfo;
 ///
 /// let cpu_info = CpuInfo::new();
 /// println!("CPU Model: {}", cpu_info.model);
 /// println!("Logical Cores: {:?}", cpu_info.total_logical_cores);
 /// println!("Physical Cores: {:?}", cpu_info.total_physical_cores);
 /// ```
-#[derive(Debug, Clone)]
+///
+/// # Serialization
+///
+/// With the `serde` feature enabled, `CpuInfo` and all nested types implement
+/// `Serialize` and `Deserialize`. Enums use serde's default externally tagged
+/// representation, so a known vendor is written as `"Intel"` and a custom one
+/// as `{"Other": "..."}`.
+/// The serialized object starts with a `schema_version` key holding
+/// [`CpuInfo::SCHEMA_VERSION`], so stored snapshots can be migrated; it is
+/// ignored when deserializing.
+///
+/// ```
+/// # #[cfg(feature = "serde")]
+/// # {
+/// use cpu_info::{CpuArchitecture, CpuInfo, DistributionCore, Endianness, Fabricant};
+///
+/// let info = CpuInfo {
+///     architecture: CpuArchitecture::X86_64,
+///     endianness: Endianness::Little,
+///     fabricant: Fabricant::Intel,
+///     model: "Intel(R) Core(TM) i7-9700K CPU @ 3.60GHz".to_string(),
+///     total_logical_cores: Some(8),
+///     online_logical_cores: Some(8),
+///     total_physical_cores: Some(8),
+///     total_sockets: Some(1),
+///     smt_enabled: Some(false),
+///     distribution: DistributionCore::Lineal { mhz: 4900, base_mhz: 3600 },
+///     caches: Vec::new(),
+///     features: Vec::new(),
+///     signature: None,
+///     microcode: None,
+///     address_bits: None,
+///     numa_nodes: Vec::new(),
+///     clusters: Vec::new(),
+///     hypervisor: None,
+/// };
+///
+/// let json = serde_json::to_string(&info).unwrap();
+/// assert!(json.starts_with(r#"{"schema_version":1,"#));
+/// assert!(json.contains(r#""fabricant":"Intel""#));
+///
+/// let back: CpuInfo = serde_json::from_str(&json).unwrap();
+/// assert_eq!(back, info);
+/// # }
+/// ```
+///
+/// # Overriding detection
+///
+/// With the `serde` feature enabled, `new()` and `try_new()` skip live
+/// detection when the `CPU_INFO_OVERRIDE` environment variable is set, and
+/// instead return the `CpuInfo` serialized as JSON in the file it points to.
+/// This keeps golden-output tests stable across CI runners. If the file can't
+/// be read or parsed, `new()` ignores it and `try_new()` returns the error.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
 pub struct CpuInfo {
     /// CPU architecture type (x86, x86_64, ARM, ARM64, etc.)
     pub architecture: CpuArchitecture,
+    /// Byte order of the CPU
+    pub endianness: Endianness,
     /// CPU manufacturer/vendor
     pub fabricant: Fabricant,
     /// CPU model name
     pub model: String,
-    /// Total number of logical cores (threads)
+    /// Total number of logical cores (threads), including offline ones
     pub total_logical_cores: Option<usize>,
+    /// Number of logical cores currently online (differs from the total with CPU hotplug)
+    pub online_logical_cores: Option<usize>,
     /// Total number of physical cores
     pub total_physical_cores: Option<usize>,
+    /// Total number of CPU sockets (physical packages)
+    pub total_sockets: Option<usize>,
+    /// Whether simultaneous multithreading (Hyper-Threading) is active
+    pub smt_enabled: Option<bool>,
     /// Core distribution information (uniform or hybrid)
     pub distribution: DistributionCore,
+    /// CPU caches, each shared cache listed once (empty if unavailable)
+    pub caches: Vec<CacheInfo>,
+    /// Instruction-set extensions supported by the CPU and usable by the OS
+    pub features: Vec<CpuFeature>,
+    /// CPUID family/model/stepping (x86 only)
+    pub signature: Option<Signature>,
+    /// Running microcode revision (x86 only)
+    pub microcode: Option<u64>,
+    /// Physical and virtual address widths
+    pub address_bits: Option<AddressBits>,
+    /// NUMA nodes and their CPUs (empty on UMA systems)
+    pub numa_nodes: Vec<NumaNode>,
+    /// Core clusters (big.LITTLE groups), empty if not detected
+    pub clusters: Vec<CpuCluster>,
+    /// Hypervisor the CPU runs under (x86 only)
+    pub hypervisor: Option<Hypervisor>,
+}
+
+/// Written by hand so the object can start with `schema_version`, which
+/// isn't a field of the struct.
+#[cfg(feature = "serde")]
+impl serde::Serialize for CpuInfo {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("CpuInfo", 19)?;
+        state.serialize_field("schema_version", &Self::SCHEMA_VERSION)?;
+        state.serialize_field("architecture", &self.architecture)?;
+        state.serialize_field("endianness", &self.endianness)?;
+        state.serialize_field("fabricant", &self.fabricant)?;
+        state.serialize_field("model", &self.model)?;
+        state.serialize_field("total_logical_cores", &self.total_logical_cores)?;
+        state.serialize_field("online_logical_cores", &self.online_logical_cores)?;
+        state.serialize_field("total_physical_cores", &self.total_physical_cores)?;
+        state.serialize_field("total_sockets", &self.total_sockets)?;
+        state.serialize_field("smt_enabled", &self.smt_enabled)?;
+        state.serialize_field("distribution", &self.distribution)?;
+        state.serialize_field("caches", &self.caches)?;
+        state.serialize_field("features", &self.features)?;
+        state.serialize_field("signature", &self.signature)?;
+        state.serialize_field("microcode", &self.microcode)?;
+        state.serialize_field("address_bits", &self.address_bits)?;
+        state.serialize_field("numa_nodes", &self.numa_nodes)?;
+        state.serialize_field("clusters", &self.clusters)?;
+        state.serialize_field("hypervisor", &self.hypervisor)?;
+        state.end()
+    }
+}
+
+impl CpuInfo {
+    /// Version of the serialized layout, written as `schema_version` by the
+    /// `serde` feature and by `to_json`.
+    ///
+    /// Bumped whenever a serialized field is added, removed, renamed or
+    /// changes shape, so consumers can detect and migrate stored snapshots.
+    /// The current layout is version 1.
+    pub const SCHEMA_VERSION: u32 = 1;
+
+    /// Returns `true` if the given instruction-set extension was detected.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cpu_info::{CpuFeature, CpuInfo};
+    ///
+    /// let cpu_info = CpuInfo::new();
+    /// if cpu_info.has_feature(CpuFeature::Avx2) {
+    ///     println!("Using the AVX2 kernel");
+    /// }
+    /// ```
+    pub fn has_feature(&self, feature: CpuFeature) -> bool {
+        self.features.contains(&feature)
+    }
+
+    /// Returns the model name without trademark marks and clock suffix.
+    ///
+    /// Strips `(R)`, `(TM)` (in either case) and `®`/`™`, a trailing
+    /// `@ 3.60GHz` clock and a trailing `CPU` word, then collapses runs of
+    /// whitespace. The `model` field itself is left verbatim.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cpu_info::CpuInfo;
+    ///
+    /// let info = CpuInfo::builder()
+    ///     .model("Intel(R) Core(TM) i7-9700K CPU @ 3.60GHz")
+    ///     .build();
+    /// assert_eq!(info.model_normalized(), "Intel Core i7-9700K");
+    ///
+    /// let amd = CpuInfo::builder().model("AMD Athlon(tm) II X2  250 Processor").build();
+    /// assert_eq!(amd.model_normalized(), "AMD Athlon II X2 250 Processor");
+    /// ```
+    pub fn model_normalized(&self) -> String {
+        let mut model = self.model.as_str();
+        if let Some((name, clock)) = model.rsplit_once(" @ ")
+            && (clock.trim_end().ends_with("GHz") || clock.trim_end().ends_with("MHz"))
+        {
+            model = name;
+        }
+
+        let mut cleaned = model.to_string();
+        for mark in ["(R)", "(r)", "(TM)", "(tm)", "®", "™"] {
+            cleaned = cleaned.replace(mark, " ");
+        }
+
+        let mut words: Vec<&str> = cleaned.split_whitespace().collect();
+        if words.len() > 1 && words.last() == Some(&"CPU") {
+            words.pop();
+        }
+        words.join(" ")
+    }
+
+    /// Formats the detected features as a space-separated string for logs.
+    ///
+    /// Features are listed in the canonical `CpuFeature` order rather than
+    /// detection order, so lines from different runs compare equal. Returns
+    /// an empty string when no feature was detected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cpu_info::{CpuFeature, CpuInfo};
+    ///
+    /// let mut info = CpuInfo::default();
+    /// info.features = vec![CpuFeature::Fma, CpuFeature::Avx2, CpuFeature::Sse2, CpuFeature::Avx];
+    /// assert_eq!(info.features_string(), "sse2 avx avx2 fma");
+    ///
+    /// assert_eq!(CpuInfo::default().features_string(), "");
+    /// ```
+    pub fn features_string(&self) -> String {
+        let mut features = self.features.clone();
+        features.sort_unstable();
+        features.dedup();
+
+        let names: Vec<String> = features.iter().map(|feature| feature.to_string()).collect();
+        names.join(" ")
+    }
+
+    /// Executes CPUID for `leaf` and `subleaf` and returns the raw registers.
+    ///
+    /// Returns `None` on non-x86 targets, when CPUID answers with zeros or
+    /// garbage (as in some sandboxes), or when `leaf` is above the
+    /// highest leaf of its range (`0x0`, `0x40000000`, `0x80000000`, ...),
+    /// since such leaves silently return data of another leaf. Leaves
+    /// without subleaves ignore `subleaf`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cpu_info::CpuInfo;
+    ///
+    /// if let Some(leaf0) = CpuInfo::cpuid(0, 0) {
+    ///     let vendor: Vec<u8> = [leaf0.ebx, leaf0.edx, leaf0.ecx]
+    ///         .iter()
+    ///         .flat_map(|reg| reg.to_le_bytes())
+    ///         .collect();
+    ///     println!("{}", String::from_utf8_lossy(&vendor));
+    ///
+    ///     // Past the highest basic leaf
+    ///     assert_eq!(CpuInfo::cpuid(leaf0.eax + 1, 0), None);
+    /// }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn cpuid(leaf: u32, subleaf: u32) -> Option<CpuidResult> {
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            Self::cpuid_checked(leaf, subleaf)
+        }
+
+        #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+        {
+            let _ = (leaf, subleaf);
+            None
+        }
+    }
+
+    /// Iterates over every logical core, whatever the distribution.
+    ///
+    /// `Hybrid` CPUs yield their per-core list as-is. `Lineal` CPUs yield
+    /// `total_logical_cores` synthesized cores at the shared frequency, with
+    /// ids `0..n` and no physical core information.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cpu_info::CpuInfo;
+    ///
+    /// let cpu_info = CpuInfo::new();
+    /// for core in cpu_info.cores() {
+    ///     println!("cpu{}: {} MHz", core.id, core.speed_mhz);
+    /// }
+    /// ```
+    pub fn cores(&self) -> impl Iterator<Item = Core> {
+        let cores: Vec<Core> = match &self.distribution {
+            DistributionCore::Hybrid { groups } => groups.clone(),
+            DistributionCore::Lineal { mhz, .. } => {
+                let count = self.total_logical_cores.unwrap_or(0) as u32;
+                (0..count).map(|id| Core::new(id, *mhz, None)).collect()
+            }
+        };
+
+        cores.into_iter()
+    }
+
+    /// Returns every logical core, fastest first.
+    ///
+    /// Cores are ordered by descending `speed_mhz`, with ties in ascending id
+    /// order. `Lineal` CPUs yield the same synthesized cores as
+    /// [`CpuInfo::cores`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cpu_info::{Core, CpuInfo};
+    ///
+    /// let info = CpuInfo::builder()
+    ///     .add_core(Core::new(0, 3800, Some(0)))
+    ///     .add_core(Core::new(1, 5000, Some(1)))
+    ///     .add_core(Core::new(2, 3800, Some(2)))
+    ///     .build();
+    ///
+    /// let ids: Vec<u32> = info.cores_by_speed_desc().iter().map(|core| core.id).collect();
+    /// assert_eq!(ids, [1, 0, 2]);
+    /// ```
+    pub fn cores_by_speed_desc(&self) -> Vec<Core> {
+        let mut cores: Vec<Core> = self.cores().collect();
+        cores.sort_by(|a, b| b.speed_mhz.cmp(&a.speed_mhz).then(a.id.cmp(&b.id)));
+        cores
+    }
+
+    /// Returns `true` if the CPU has cores running at different frequencies.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cpu_info::{Core, CpuInfo};
+    ///
+    /// let info = CpuInfo::builder()
+    ///     .add_core(Core::new(0, 5000, Some(0)))
+    ///     .add_core(Core::new(1, 3800, Some(1)))
+    ///     .build();
+    /// assert!(info.is_hybrid());
+    /// assert_eq!(info.max_frequency_mhz(), 5000);
+    /// ```
+    pub fn is_hybrid(&self) -> bool {
+        matches!(self.distribution, DistributionCore::Hybrid { .. })
+    }
+
+    /// Returns how many logical cores are performance cores (P-cores).
+    ///
+    /// Counted from the `core_type` of the `Hybrid` cores. Returns `None` on
+    /// `Lineal` CPUs, which have no core types, and on `Hybrid` CPUs where no
+    /// core reports a type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cpu_info::{Core, CoreType, CpuInfo};
+    ///
+    /// let core = |id: u32, mhz: u32, core_type: CoreType| {
+    ///     let mut core = Core::new(id, mhz, Some(id));
+    ///     core.core_type = Some(core_type);
+    ///     core
+    /// };
+    ///
+    /// // 6 P-cores and 8 E-cores
+    /// let mut builder = CpuInfo::builder();
+    /// for id in 0..6 {
+    ///     builder = builder.add_core(core(id, 4900, CoreType::Performance));
+    /// }
+    /// for id in 6..14 {
+    ///     builder = builder.add_core(core(id, 3600, CoreType::Efficient));
+    /// }
+    /// let info = builder.build();
+    /// assert_eq!(info.performance_core_count(), Some(6));
+    /// assert_eq!(info.efficiency_core_count(), Some(8));
+    ///
+    /// let lineal = CpuInfo::builder().add_core(Core::new(0, 4000, Some(0))).build();
+    /// assert_eq!(lineal.performance_core_count(), None);
+    /// assert_eq!(lineal.efficiency_core_count(), None);
+    /// ```
+    pub fn performance_core_count(&self) -> Option<usize> {
+        self.count_core_type(CoreType::Performance)
+    }
+
+    /// Returns how many logical cores are efficiency cores (E-cores).
+    ///
+    /// Counted like [`CpuInfo::performance_core_count`], and `None` in the
+    /// same cases.
+    pub fn efficiency_core_count(&self) -> Option<usize> {
+        self.count_core_type(CoreType::Efficient)
+    }
+
+    /// Counts the `Hybrid` cores of one type, if any core reports a type.
+    fn count_core_type(&self, core_type: CoreType) -> Option<usize> {
+        let DistributionCore::Hybrid { groups } = &self.distribution else {
+            return None;
+        };
+
+        if groups.iter().all(|core| core.core_type.is_none()) {
+            return None;
+        }
+
+        Some(groups.iter().filter(|core| core.core_type == Some(core_type)).count())
+    }
+
+    /// Returns the highest maximum core frequency in MHz (0 if unknown).
+    ///
+    /// This is the shared `mhz` for `Lineal` CPUs and the fastest core for
+    /// `Hybrid` ones.
+    pub fn max_frequency_mhz(&self) -> u32 {
+        match &self.distribution {
+            DistributionCore::Lineal { mhz, .. } => *mhz,
+            DistributionCore::Hybrid { groups } => {
+                groups.iter().map(|core| core.speed_mhz).max().unwrap_or(0)
+            }
+        }
+    }
+
+    /// Returns the number of hardware threads per physical core (SMT width).
+    ///
+    /// Computed as `total_logical_cores / total_physical_cores`, e.g. 2 on
+    /// x86 with Hyper-Threading or 4 on SMT-4 POWER. Returns `None` if either
+    /// count is unknown or the division isn't exact, which happens with
+    /// non-uniform SMT (e.g. hybrid parts where only P-cores have two
+    /// threads), so no misleading average is reported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cpu_info::CpuInfo;
+    ///
+    /// let smt2 = CpuInfo::builder().logical_cores(16).physical_cores(8).build();
+    /// assert_eq!(smt2.threads_per_core(), Some(2));
+    ///
+    /// // 8 P-cores with 2 threads plus 8 E-cores with 1
+    /// let hybrid = CpuInfo::builder().logical_cores(24).physical_cores(16).build();
+    /// assert_eq!(hybrid.threads_per_core(), None);
+    /// ```
+    pub fn threads_per_core(&self) -> Option<usize> {
+        let logical = self.total_logical_cores?;
+        let physical = self.total_physical_cores?;
+        (physical > 0 && logical % physical == 0).then(|| logical / physical)
+    }
+
+    /// Returns a logical core count that is always usable, e.g. to size a
+    /// thread pool.
+    ///
+    /// The first non-zero value of this chain is returned:
+    ///
+    /// 1. `online_logical_cores`
+    /// 2. `total_logical_cores`
+    /// 3. with `std`, [`std::thread::available_parallelism`], which asks the
+    ///    OS directly (`sysconf(_SC_NPROCESSORS_ONLN)` or the affinity mask
+    ///    on Unix, `GetSystemInfo` on Windows)
+    /// 4. 1
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cpu_info::CpuInfo;
+    ///
+    /// let info = CpuInfo::builder().logical_cores(8).build();
+    /// assert_eq!(info.logical_cores_nonzero().get(), 8);
+    ///
+    /// // Nothing detected: the OS (or 1) answers instead
+    /// assert!(CpuInfo::default().logical_cores_nonzero().get() >= 1);
+    /// ```
+    pub fn logical_cores_nonzero(&self) -> NonZeroUsize {
+        let detected = self
+            .online_logical_cores
+            .or(self.total_logical_cores)
+            .and_then(NonZeroUsize::new);
+
+        #[cfg(feature = "std")]
+        let detected = detected.or_else(|| std::thread::available_parallelism().ok());
+
+        detected.unwrap_or(NonZeroUsize::MIN)
+    }
+
+    /// Returns the logical core IDs attached to NUMA node `node`.
+    ///
+    /// Returns an empty vec when the node doesn't exist, including on systems
+    /// without NUMA information.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cpu_info::{CpuInfo, NumaNode};
+    ///
+    /// let info = CpuInfo {
+    ///     numa_nodes: vec![
+    ///         NumaNode { id: 0, cpus: vec![0, 1, 2, 3] },
+    ///         NumaNode { id: 1, cpus: vec![4, 5, 6, 7] },
+    ///     ],
+    ///     ..CpuInfo::default()
+    /// };
+    /// assert_eq!(info.cores_on_node(1), [4, 5, 6, 7]);
+    /// assert!(info.cores_on_node(2).is_empty());
+    /// ```
+    pub fn cores_on_node(&self, node: u32) -> Vec<u32> {
+        self.numa_nodes
+            .iter()
+            .find(|numa_node| numa_node.id == node)
+            .map(|numa_node| numa_node.cpus.clone())
+            .unwrap_or_default()
+    }
+
+    /// Returns the combined size in bytes of every cache at `level`.
+    ///
+    /// Each cache instance counts once, so an L3 shared by all cores of a
+    /// socket is added once per socket. Caches read from sysfs are already
+    /// listed per instance. Caches from CPUID describe a single instance, so
+    /// their count is estimated as `total_logical_cores / shared_by`,
+    /// rounded up. Returns 0 when no cache at that level is known.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cpu_info::{CacheInfo, CacheKind, CpuInfo};
+    ///
+    /// // One 16 MiB L3 slice per socket
+    /// let l3 = |cpus: core::ops::Range<u32>| CacheInfo {
+    ///     level: 3,
+    ///     kind: CacheKind::Unified,
+    ///     size_bytes: 16 * 1024 * 1024,
+    ///     line_size: 64,
+    ///     shared_by: 8,
+    ///     shared_cpu_ids: cpus.collect(),
+    /// };
+    /// let info = CpuInfo {
+    ///     total_logical_cores: Some(16),
+    ///     caches: vec![l3(0..8), l3(8..16)],
+    ///     ..CpuInfo::default()
+    /// };
+    /// assert_eq!(info.total_cache_bytes(3), 32 * 1024 * 1024);
+    /// assert_eq!(info.l3_bytes(), 32 * 1024 * 1024);
+    /// assert_eq!(info.total_cache_bytes(2), 0);
+    /// ```
+    pub fn total_cache_bytes(&self, level: u8) -> u64 {
+        self.sum_cache_bytes(|cache| cache.level == level)
+    }
+
+    /// Returns the combined size in bytes of all L1 data caches.
+    ///
+    /// Instruction caches are left out; see [`CpuInfo::total_cache_bytes`]
+    /// for how instances are counted.
+    pub fn l1d_bytes(&self) -> u64 {
+        self.sum_cache_bytes(|cache| cache.level == 1 && cache.kind == CacheKind::Data)
+    }
+
+    /// Returns the combined size in bytes of all L2 caches.
+    ///
+    /// Shorthand for `total_cache_bytes(2)`.
+    pub fn l2_bytes(&self) -> u64 {
+        self.total_cache_bytes(2)
+    }
+
+    /// Returns the combined size in bytes of all L3 caches.
+    ///
+    /// Shorthand for `total_cache_bytes(3)`.
+    pub fn l3_bytes(&self) -> u64 {
+        self.total_cache_bytes(3)
+    }
+
+    /// Sums the size of every cache instance matching `filter`.
+    fn sum_cache_bytes(&self, filter: impl Fn(&CacheInfo) -> bool) -> u64 {
+        self.caches
+            .iter()
+            .filter(|cache| filter(cache))
+            .map(|cache| {
+                let instances = match self.total_logical_cores {
+                    Some(logical) if cache.shared_cpu_ids.is_empty() && cache.shared_by > 0 => {
+                        logical.div_ceil(cache.shared_by)
+                    }
+                    _ => 1,
+                };
+                cache.size_bytes * instances as u64
+            })
+            .sum()
+    }
+
+    /// Returns a stable identifier derived from the CPU's static characteristics.
+    ///
+    /// Only these fields contribute, in this order:
+    ///
+    /// 1. `architecture` (variant name, e.g. `X86_64`)
+    /// 2. `fabricant` (variant name, or the vendor string for `Other`)
+    /// 3. `model`
+    /// 4. `total_physical_cores` (empty when unknown)
+    ///
+    /// Frequencies, online cores, features and every other field are left
+    /// out, so the value doesn't change across boots or governor settings.
+    /// The fields are joined with NUL bytes and hashed with 64-bit FNV-1a,
+    /// which unlike [`core::hash::Hash`] is fixed across Rust releases. This
+    /// is fingerprint version 1; any change to the inputs or the algorithm
+    /// will be called out as a breaking change.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cpu_info::{Core, CpuArchitecture, CpuInfo, Fabricant};
+    ///
+    /// let info = CpuInfo::builder()
+    ///     .model("Test CPU")
+    ///     .architecture(CpuArchitecture::X86_64)
+    ///     .fabricant(Fabricant::Intel)
+    ///     .add_core(Core::new(0, 5000, Some(0)))
+    ///     .add_core(Core::new(1, 3800, Some(1)))
+    ///     .build();
+    ///
+    /// // Different clocks, same fingerprint
+    /// let mut throttled = info.clone();
+    /// throttled.distribution = cpu_info::DistributionCore::Lineal { mhz: 800, base_mhz: 0 };
+    /// assert_eq!(info.fingerprint(), throttled.fingerprint());
+    /// assert_eq!(info.fingerprint(), 0x667d_aba5_5b96_bd60);
+    /// ```
+    pub fn fingerprint(&self) -> u64 {
+        const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+        let mut input = String::new();
+        let _ = write!(input, "{:?}\0", self.architecture);
+        match &self.fabricant {
+            Fabricant::Other(vendor) => input.push_str(vendor),
+            fabricant => {
+                let _ = write!(input, "{:?}", fabricant);
+            }
+        }
+        let _ = write!(input, "\0{}\0", self.model);
+        if let Some(cores) = self.total_physical_cores {
+            let _ = write!(input, "{}", cores);
+        }
+
+        input.bytes().fold(FNV_OFFSET_BASIS, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+        })
+    }
+
+    /// Flattens the most commonly displayed values into a [`CpuSummary`].
+    ///
+    /// Unknown core counts become 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cpu_info::{Core, CpuArchitecture, CpuInfo};
+    ///
+    /// let info = CpuInfo::builder()
+    ///     .model("Test CPU")
+    ///     .architecture(CpuArchitecture::X86_64)
+    ///     .add_core(Core::new(0, 5000, Some(0)))
+    ///     .add_core(Core::new(1, 3800, Some(1)))
+    ///     .build();
+    ///
+    /// let summary = info.summary();
+    /// assert_eq!(summary.model, "Test CPU");
+    /// assert_eq!(summary.arch, "x86_64");
+    /// assert_eq!((summary.physical, summary.logical), (2, 2));
+    /// assert_eq!(summary.max_mhz, 5000);
+    /// assert!(summary.hybrid);
+    /// ```
+    pub fn summary(&self) -> CpuSummary {
+        CpuSummary {
+            model: self.model.clone(),
+            arch: self.architecture.to_string(),
+            physical: self.total_physical_cores.unwrap_or(0),
+            logical: self.total_logical_cores.unwrap_or(0),
+            max_mhz: self.max_frequency_mhz(),
+            hybrid: self.is_hybrid(),
+        }
+    }
+
+    /// Lists the fields that differ between `self` (before) and `other` (after).
+    ///
+    /// Every public field is compared except the per-core data, whose current
+    /// clocks change constantly; the distribution is compared through
+    /// `hybrid` and `max_frequency_mhz` instead. Values are rendered with
+    /// `Debug`. An empty list means both snapshots describe the same CPU.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cpu_info::{Core, CpuInfo};
+    ///
+    /// let before = CpuInfo::builder()
+    ///     .model("Test CPU")
+    ///     .add_core(Core::new(0, 4200, Some(0)))
+    ///     .add_core(Core::new(1, 4200, Some(1)))
+    ///     .build();
+    /// let after = CpuInfo::builder()
+    ///     .model("Test CPU")
+    ///     .add_core(Core::new(0, 4600, Some(0)))
+    ///     .add_core(Core::new(1, 4600, Some(1)))
+    ///     .build();
+    ///
+    /// let changes = before.diff(&after);
+    /// assert_eq!(changes.len(), 1);
+    /// assert_eq!(changes[0].field, "max_frequency_mhz");
+    /// assert_eq!((changes[0].old.as_str(), changes[0].new.as_str()), ("4200", "4600"));
+    /// ```
+    pub fn diff(&self, other: &CpuInfo) -> Vec<CpuInfoChange> {
+        let mut changes = Vec::new();
+
+        CpuInfoChange::compare(&mut changes, "architecture", &self.architecture, &other.architecture);
+        CpuInfoChange::compare(&mut changes, "endianness", &self.endianness, &other.endianness);
+        CpuInfoChange::compare(&mut changes, "fabricant", &self.fabricant, &other.fabricant);
+        CpuInfoChange::compare(&mut changes, "model", &self.model, &other.model);
+        CpuInfoChange::compare(&mut changes, "total_logical_cores", &self.total_logical_cores, &other.total_logical_cores);
+        CpuInfoChange::compare(&mut changes, "online_logical_cores", &self.online_logical_cores, &other.online_logical_cores);
+        CpuInfoChange::compare(&mut changes, "total_physical_cores", &self.total_physical_cores, &other.total_physical_cores);
+        CpuInfoChange::compare(&mut changes, "total_sockets", &self.total_sockets, &other.total_sockets);
+        CpuInfoChange::compare(&mut changes, "smt_enabled", &self.smt_enabled, &other.smt_enabled);
+        CpuInfoChange::compare(&mut changes, "hybrid", &self.is_hybrid(), &other.is_hybrid());
+        CpuInfoChange::compare(&mut changes, "max_frequency_mhz", &self.max_frequency_mhz(), &other.max_frequency_mhz());
+        CpuInfoChange::compare(&mut changes, "caches", &self.caches, &other.caches);
+        CpuInfoChange::compare(&mut changes, "features", &self.features, &other.features);
+        CpuInfoChange::compare(&mut changes, "signature", &self.signature, &other.signature);
+        CpuInfoChange::compare(&mut changes, "microcode", &self.microcode, &other.microcode);
+        CpuInfoChange::compare(&mut changes, "address_bits", &self.address_bits, &other.address_bits);
+        CpuInfoChange::compare(&mut changes, "numa_nodes", &self.numa_nodes, &other.numa_nodes);
+        CpuInfoChange::compare(&mut changes, "clusters", &self.clusters, &other.clusters);
+        CpuInfoChange::compare(&mut changes, "hypervisor", &self.hypervisor, &other.hypervisor);
+
+        changes
+    }
+
+    /// Starts building a `CpuInfo` by hand, e.g. as a test fixture.
+    ///
+    /// See [`CpuInfoBuilder`] for the available setters and defaults.
+    pub fn builder() -> CpuInfoBuilder {
+        CpuInfoBuilder::default()
+    }
+}
+
+#[cfg(any(
+    all(feature = "linux", any(target_os = "linux", target_os = "android")),
+    all(feature = "windows", target_os = "windows"),
+    all(feature = "macos", target_os = "macos"),
+    all(feature = "freebsd", target_os = "freebsd"),
+    all(feature = "openbsd", target_os = "openbsd"),
+    all(feature = "netbsd", target_os = "netbsd"),
+    all(feature = "illumos", any(target_os = "illumos", target_os = "solaris")),
+    all(feature = "haiku", target_os = "haiku"),
+    all(feature = "std", target_family = "wasm"),
+))]
+impl CpuInfo {
+    /// Returns a process-wide `CpuInfo`, detected on first use.
+    ///
+    /// Detection runs once, even when called from several threads at the
+    /// same time; later calls return the same reference. The snapshot never
+    /// changes, so dynamic data such as `current_mhz` stays at its first
+    /// value. Use [`CpuInfo::new`] and [`CpuInfo::refresh_frequencies`] on
+    /// an owned instance for live data.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cpu_info::CpuInfo;
+    ///
+    /// let threads = CpuInfo::cached().total_logical_cores.unwrap_or(1);
+    /// ```
+    pub fn cached() -> &'static CpuInfo {
+        static CACHED: std::sync::OnceLock<CpuInfo> = std::sync::OnceLock::new();
+        CACHED.get_or_init(CpuInfo::new)
+    }
+
+    /// Detects CPU information without blocking the async runtime.
+    ///
+    /// Runs [`CpuInfo::new`] on Tokio's blocking thread pool, so the sysfs or
+    /// registry reads don't stall other tasks. The result is identical to
+    /// the synchronous call. Requires the `async` feature and a Tokio runtime.
+    ///
+    /// # Panics
+    ///
+    /// Re-raises a panic from detection, and panics if the runtime shuts
+    /// down before detection finishes.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cpu_info::CpuInfo;
+    ///
+    /// # async fn run() {
+    /// let cpu_info = CpuInfo::new_async().await;
+    /// println!("{}", cpu_info.model);
+    /// # }
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn new_async() -> CpuInfo {
+        match tokio::task::spawn_blocking(CpuInfo::new).await {
+            Ok(info) => info,
+            Err(e) => std::panic::resume_unwind(e.into_panic()),
+        }
+    }
+
+    /// Estimates the CPU clock by counting TSC ticks over `duration`.
+    ///
+    /// Meant as a fallback when the platform reports no frequency. The
+    /// current thread sleeps for `duration`, so longer values are more
+    /// accurate but block longer. An invariant TSC ticks at the nominal
+    /// (base) clock whatever the power state, so this approximates the base
+    /// frequency, not the boost one. Returns `None` on non-x86 CPUs and on
+    /// x86 CPUs without an invariant TSC. Requires the `estimate` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use cpu_info::CpuInfo;
+    ///
+    /// if let Some(mhz) = CpuInfo::measure_frequency_mhz(Duration::from_millis(100)) {
+    ///     println!("~{} MHz", mhz);
+    /// }
+    /// ```
+    #[cfg(feature = "estimate")]
+    pub fn measure_frequency_mhz(duration: std::time::Duration) -> Option<u32> {
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            Self::measure_tsc_mhz(duration)
+        }
+
+        #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+        {
+            let _ = duration;
+            None
+        }
+    }
+
+    /// Samples the current frequency of every core on a background thread.
+    ///
+    /// The cores of this `CpuInfo` are reused as-is, so each tick only
+    /// re-reads the current frequencies (see
+    /// [`CpuInfo::refresh_frequencies`]) and passes the updated cores to
+    /// `callback`, once right away and then every `interval`. `Lineal` CPUs
+    /// are sampled through the cores of [`CpuInfo::cores`]. Sampling stops
+    /// when the returned [`FrequencyWatch`] is stopped or dropped.
+    ///
+    /// Not available on WebAssembly, which has no threads.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use cpu_info::CpuInfo;
+    ///
+    /// let watch = CpuInfo::new().watch_frequencies(Duration::from_secs(1), |cores| {
+    ///     let mhz: Vec<u32> = cores.iter().map(|core| core.current_mhz).collect();
+    ///     println!("{:?}", mhz);
+    /// });
+    /// std::thread::sleep(Duration::from_secs(10));
+    /// watch.stop();
+    /// ```
+    #[cfg(not(target_family = "wasm"))]
+    pub fn watch_frequencies<F>(&self, interval: std::time::Duration, mut callback: F) -> FrequencyWatch
+    where
+        F: FnMut(&[Core]) + Send + 'static,
+    {
+        let mut snapshot = CpuInfo {
+            distribution: DistributionCore::Hybrid { groups: self.cores().collect() },
+            ..CpuInfo::default()
+        };
+
+        let stop = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let stopped = std::sync::Arc::clone(&stop);
+        let is_stopped = move || stopped.load(std::sync::atomic::Ordering::Relaxed);
+
+        let thread = std::thread::spawn(move || {
+            while !is_stopped() {
+                snapshot.refresh_frequencies();
+                if let DistributionCore::Hybrid { groups } = &snapshot.distribution {
+                    callback(groups);
+                }
+
+                // Parking can wake up early, so sleep until the deadline
+                let deadline = std::time::Instant::now() + interval;
+                while !is_stopped() {
+                    let now = std::time::Instant::now();
+                    if now >= deadline {
+                        break;
+                    }
+                    std::thread::park_timeout(deadline - now);
+                }
+            }
+        });
+
+        FrequencyWatch { stop, thread: Some(thread) }
+    }
+}
+
+/// Background frequency sampling started by [`CpuInfo::watch_frequencies`].
+///
+/// Dropping the handle stops the sampling thread and waits for it, like
+/// [`FrequencyWatch::stop`].
+#[cfg(all(feature = "std", not(target_family = "wasm")))]
+#[derive(Debug)]
+pub struct FrequencyWatch {
+    stop: std::sync::Arc<std::sync::atomic::AtomicBool>,
+    thread: Option<std::thread::JoinHandle<()>>,
+}
+
+#[cfg(all(feature = "std", not(target_family = "wasm")))]
+impl FrequencyWatch {
+    /// Stops sampling and waits for the current callback to return.
+    pub fn stop(self) {
+        drop(self);
+    }
+}
+
+#[cfg(all(feature = "std", not(target_family = "wasm")))]
+impl Drop for FrequencyWatch {
+    fn drop(&mut self) {
+        self.stop.store(true, std::sync::atomic::Ordering::Relaxed);
+        if let Some(thread) = self.thread.take() {
+            thread.thread().unpark();
+            // A panic in the callback already ended the thread; don't repeat it here
+            let _ = thread.join();
+        }
+    }
+}
+
+/// Builder for hand-made [`CpuInfo`] values.
+///
+/// Unset fields fall back to the same placeholders detection uses
+/// (`Unknown`, `None`, empty lists). When no distribution is given, it is
+/// derived from the added cores exactly like on a real system, and the core
+/// counts default to what those cores describe. All logical cores are
+/// reported online.
+///
+/// # Examples
+///
+/// ```
+/// use cpu_info::{CpuArchitecture, Core, CpuInfo, DistributionCore};
+///
+/// let info = CpuInfo::builder()
+///     .model("Test CPU")
+///     .architecture(CpuArchitecture::X86_64)
+///     .add_core(Core::new(0, 5000, Some(0)))
+///     .add_core(Core::new(1, 5000, Some(1)))
+///     .add_core(Core::new(2, 3800, Some(2)))
+///     .build();
+///
+/// assert_eq!(info.total_logical_cores, Some(3));
+/// assert!(matches!(info.distribution, DistributionCore::Hybrid { .. }));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CpuInfoBuilder {
+    architecture: Option<CpuArchitecture>,
+    fabricant: Option<Fabricant>,
+    model: Option<String>,
+    logical_cores: Option<usize>,
+    physical_cores: Option<usize>,
+    distribution: Option<DistributionCore>,
+    cores: Vec<Core>,
+}
+
+impl CpuInfoBuilder {
+    /// Sets the CPU model name.
+    pub fn model(mut self, model: impl Into<String>) -> Self {
+        self.model = Some(model.into());
+        self
+    }
+
+    /// Sets the CPU architecture.
+    pub fn architecture(mut self, architecture: CpuArchitecture) -> Self {
+        self.architecture = Some(architecture);
+        self
+    }
+
+    /// Sets the CPU manufacturer.
+    pub fn fabricant(mut self, fabricant: Fabricant) -> Self {
+        self.fabricant = Some(fabricant);
+        self
+    }
+
+    /// Sets the number of logical cores, overriding the count of added cores.
+    pub fn logical_cores(mut self, count: usize) -> Self {
+        self.logical_cores = Some(count);
+        self
+    }
+
+    /// Sets the number of physical cores, overriding the added cores' physical ids.
+    pub fn physical_cores(mut self, count: usize) -> Self {
+        self.physical_cores = Some(count);
+        self
+    }
+
+    /// Sets the distribution explicitly instead of deriving it from the cores.
+    pub fn distribution(mut self, distribution: DistributionCore) -> Self {
+        self.distribution = Some(distribution);
+        self
+    }
+
+    /// Adds a core used to derive the distribution and core counts.
+    pub fn add_core(mut self, core: Core) -> Self {
+        self.cores.push(core);
+        self
+    }
+
+    /// Builds the `CpuInfo`.
+    pub fn build(self) -> CpuInfo {
+        let cores = self.cores;
+
+        let total_logical_cores = self
+            .logical_cores
+            .or((!cores.is_empty()).then_some(cores.len()));
+
+        let mut physical_ids: Vec<u32> = cores.iter().filter_map(|c| c.physical_core_id).collect();
+        physical_ids.sort_unstable();
+        physical_ids.dedup();
+        let total_physical_cores = self
+            .physical_cores
+            .or((!physical_ids.is_empty()).then_some(physical_ids.len()));
+
+        CpuInfo {
+            architecture: self.architecture.unwrap_or(CpuArchitecture::Unknown),
+            fabricant: self.fabricant.unwrap_or(Fabricant::Unknown),
+            model: self.model.unwrap_or_else(|| "Unknown".to_string()),
+            total_logical_cores,
+            online_logical_cores: total_logical_cores,
+            total_physical_cores,
+            smt_enabled: CpuInfo::smt_from_counts(total_logical_cores, total_physical_cores),
+            distribution: self
+                .distribution
+                .unwrap_or_else(|| CpuInfo::detect_distribution(&cores)),
+            ..CpuInfo::default()
+        }
+    }
+}
+
+/// Placeholder for a CPU that hasn't been detected yet.
+///
+/// Unlike [`CpuInfo::new`], this performs no detection: architecture and
+/// vendor are `Unknown` (endianness is known at compile time), the model is `"Unknown"`, every count is `None`
+/// and the distribution is `Lineal` at 0 MHz.
+///
+/// # Examples
+///
+/// ```
+/// use cpu_info::{CpuArchitecture, CpuInfo, DistributionCore};
+///
+/// let info = CpuInfo::default();
+/// assert_eq!(info.architecture, CpuArchitecture::Unknown);
+/// assert_eq!(info.total_logical_cores, None);
+/// assert_eq!(info.distribution, DistributionCore::Lineal { mhz: 0, base_mhz: 0 });
+/// ```
+impl Default for CpuInfo {
+    fn default() -> Self {
+        Self {
+            architecture: CpuArchitecture::Unknown,
+            endianness: CpuInfo::get_endianness(),
+            fabricant: Fabricant::Unknown,
+            model: "Unknown".to_string(),
+            total_logical_cores: None,
+            online_logical_cores: None,
+            total_physical_cores: None,
+            total_sockets: None,
+            smt_enabled: None,
+            distribution: DistributionCore::Lineal { mhz: 0, base_mhz: 0 },
+            caches: Vec::new(),
+            features: Vec::new(),
+            signature: None,
+            microcode: None,
+            address_bits: None,
+            numa_nodes: Vec::new(),
+            clusters: Vec::new(),
+            hypervisor: None,
+        }
+    }
 }
 
+/// Human-friendly summary of the CPU.
+///
+/// Hybrid CPUs are summarized per frequency group instead of listing every
+/// core; use `{:?}` for the full dump.
+///
+/// # Examples
+///
+/// ```
+/// use cpu_info::{CpuArchitecture, CpuInfo, DistributionCore, Endianness, Fabricant};
+///
+/// let info = CpuInfo {
+///     architecture: CpuArchitecture::X86_64,
+///     endianness: Endianness::Little,
+///     fabricant: Fabricant::Intel,
+///     model: "Intel(R) Core(TM) i7-9700K CPU @ 3.60GHz".to_string(),
+///     total_logical_cores: Some(8),
+///     online_logical_cores: Some(8),
+///     total_physical_cores: Some(8),
+///     total_sockets: Some(1),
+///     smt_enabled: Some(false),
+///     distribution: DistributionCore::Lineal { mhz: 4900, base_mhz: 3600 },
+///     caches: Vec::new(),
+///     features: Vec::new(),
+///     signature: None,
+///     microcode: None,
+///     address_bits: None,
+///     numa_nodes: Vec::new(),
+///     clusters: Vec::new(),
+///     hypervisor: None,
+/// };
+///
+/// assert_eq!(
+///     info.to_string(),
+///     "Intel(R) Core(TM) i7-9700K CPU @ 3.60GHz (Intel)\nx86_64 little-endian / 8 physical, 8 logical cores @ 4900 MHz"
+/// );
+/// ```
+impl fmt::Display for CpuInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let count = |n: Option<usize>| n.map_or("?".to_string(), |n| n.to_string());
 
+        writeln!(f, "{} ({})", self.model, self.fabricant)?;
+        write!(
+            f,
+            "{} {} / {} physical, {} logical cores",
+            self.architecture,
+            self.endianness,
+            count(self.total_physical_cores),
+            count(self.total_logical_cores)
+        )?;
+
+        match &self.distribution {
+            DistributionCore::Lineal { mhz: 0, .. } => Ok(()),
+            DistributionCore::Lineal { mhz, .. } => write!(f, " @ {} MHz", mhz),
+            DistributionCore::Hybrid { .. } => {
+                for (mhz, cores) in self.distribution.frequency_groups() {
+                    write!(f, "\n  {} cores @ {} MHz", cores.len(), mhz)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
 
 /// CPU architecture type.
 ///
 /// Represents the instruction set architecture of the CPU.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CpuArchitecture {
     /// 32-bit x86
     X86,
@@ -53,41 +1207,392 @@ pub enum CpuArchitecture {
     ARM,
     /// 64-bit ARM (AArch64)
     ARM64,
+    /// 32-bit RISC-V
+    RISCV32,
+    /// 64-bit RISC-V
+    RISCV64,
+    /// IBM Z (64-bit)
+    S390X,
+    /// 32-bit PowerPC
+    PowerPC,
+    /// 64-bit PowerPC (POWER)
+    PowerPC64,
+    /// 64-bit LoongArch (Loongson)
+    LoongArch64,
     /// Unknown or unsupported architecture
     Unknown,
 }
 
+impl CpuArchitecture {
+    /// Returns the native pointer width in bits, or `None` for `Unknown`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cpu_info::CpuArchitecture;
+    ///
+    /// assert_eq!(CpuArchitecture::ARM64.bits(), Some(64));
+    /// assert_eq!(CpuArchitecture::RISCV32.bits(), Some(32));
+    /// assert_eq!(CpuArchitecture::Unknown.bits(), None);
+    /// ```
+    pub fn bits(&self) -> Option<u8> {
+        match self {
+            CpuArchitecture::X86
+            | CpuArchitecture::ARM
+            | CpuArchitecture::RISCV32
+            | CpuArchitecture::PowerPC => Some(32),
+            CpuArchitecture::X86_64
+            | CpuArchitecture::ARM64
+            | CpuArchitecture::RISCV64
+            | CpuArchitecture::S390X
+            | CpuArchitecture::PowerPC64
+            | CpuArchitecture::LoongArch64 => Some(64),
+            CpuArchitecture::Unknown => None,
+        }
+    }
+
+    /// Returns `true` for 32-bit and 64-bit ARM.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cpu_info::CpuArchitecture;
+    ///
+    /// assert!(CpuArchitecture::ARM.is_arm());
+    /// assert!(CpuArchitecture::ARM64.is_arm());
+    /// assert!(!CpuArchitecture::X86_64.is_arm());
+    /// ```
+    pub fn is_arm(&self) -> bool {
+        matches!(self, CpuArchitecture::ARM | CpuArchitecture::ARM64)
+    }
+
+    /// Returns `true` for 32-bit and 64-bit x86.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cpu_info::CpuArchitecture;
+    ///
+    /// assert!(CpuArchitecture::X86.is_x86());
+    /// assert!(CpuArchitecture::X86_64.is_x86());
+    /// assert!(!CpuArchitecture::ARM64.is_x86());
+    /// ```
+    pub fn is_x86(&self) -> bool {
+        matches!(self, CpuArchitecture::X86 | CpuArchitecture::X86_64)
+    }
+
+    /// Returns `true` for 64-bit architectures, `false` for 32-bit and `Unknown`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cpu_info::CpuArchitecture;
+    ///
+    /// assert!(CpuArchitecture::RISCV64.is_64bit());
+    /// assert!(!CpuArchitecture::ARM.is_64bit());
+    /// assert!(!CpuArchitecture::Unknown.is_64bit());
+    /// ```
+    pub fn is_64bit(&self) -> bool {
+        self.bits() == Some(64)
+    }
+}
+
+impl fmt::Display for CpuArchitecture {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            CpuArchitecture::X86 => "x86",
+            CpuArchitecture::X86_64 => "x86_64",
+            CpuArchitecture::ARM => "ARM",
+            CpuArchitecture::ARM64 => "ARM64",
+            CpuArchitecture::RISCV32 => "RISC-V 32",
+            CpuArchitecture::RISCV64 => "RISC-V 64",
+            CpuArchitecture::S390X => "s390x",
+            CpuArchitecture::PowerPC => "PowerPC",
+            CpuArchitecture::PowerPC64 => "PowerPC 64",
+            CpuArchitecture::LoongArch64 => "LoongArch64",
+            CpuArchitecture::Unknown => "Unknown",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Byte order of the CPU.
+///
+/// Like the architecture, this is fixed at compile time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Endianness {
+    /// Least significant byte first (x86, most ARM and RISC-V systems)
+    Little,
+    /// Most significant byte first (e.g. s390x, some PowerPC and MIPS)
+    Big,
+}
+
+impl fmt::Display for Endianness {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Endianness::Little => "little-endian",
+            Endianness::Big => "big-endian",
+        };
+        write!(f, "{}", name)
+    }
+}
+
 /// CPU manufacturer/vendor.
 ///
 /// Represents the company that designed or manufactured the CPU.
-#[derive(Debug, Clone)]
+///
+/// Vendors are ordered for display: `Intel`, then `Amd`, then every other
+/// named vendor alphabetically by display name (ignoring case), with
+/// `Unknown` last.
+///
+/// ```
+/// use cpu_info::Fabricant;
+///
+/// let mut vendors = vec![
+///     Fabricant::Unknown,
+///     Fabricant::Qualcomm,
+///     Fabricant::Other("Ampere".to_string()),
+///     Fabricant::Amd,
+///     Fabricant::Apple,
+///     Fabricant::Intel,
+/// ];
+/// vendors.sort();
+/// assert_eq!(vendors, [
+///     Fabricant::Intel,
+///     Fabricant::Amd,
+///     Fabricant::Other("Ampere".to_string()),
+///     Fabricant::Apple,
+///     Fabricant::Qualcomm,
+///     Fabricant::Unknown,
+/// ]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Fabricant {
     /// Intel Corporation
     Intel,
     /// Advanced Micro Devices (AMD)
     Amd,
+    /// Hygon (AMD Zen licensee, `HygonGenuine`)
+    Hygon,
+    /// VIA Technologies and its Centaur design team (`CentaurHauls`, `VIA VIA VIA `)
+    Via,
+    /// Zhaoxin (`  Shanghai  `)
+    Zhaoxin,
+    /// Arm Ltd. (stock Cortex and Neoverse cores)
+    Arm,
+    /// Qualcomm (Snapdragon, Kryo, Oryon)
+    Qualcomm,
+    /// Apple (Apple Silicon)
+    Apple,
+    /// Broadcom (custom cores such as Brahma and Vulcan)
+    Broadcom,
+    /// Nvidia (Denver, Carmel, Grace)
+    Nvidia,
     /// Other manufacturer with vendor string
     Other(String),
     /// Unknown manufacturer
     Unknown,
 }
 
+impl fmt::Display for Fabricant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.display_name())
+    }
+}
+
+impl Fabricant {
+    /// Name shown by `Display`, without allocating.
+    fn display_name(&self) -> &str {
+        match self {
+            Fabricant::Intel => "Intel",
+            Fabricant::Amd => "AMD",
+            Fabricant::Hygon => "Hygon",
+            Fabricant::Via => "VIA",
+            Fabricant::Zhaoxin => "Zhaoxin",
+            Fabricant::Arm => "ARM",
+            Fabricant::Qualcomm => "Qualcomm",
+            Fabricant::Apple => "Apple",
+            Fabricant::Broadcom => "Broadcom",
+            Fabricant::Nvidia => "Nvidia",
+            Fabricant::Other(vendor) => vendor,
+            Fabricant::Unknown => "Unknown",
+        }
+    }
+
+    /// Sort group: Intel, AMD, named vendors, then unknown.
+    fn display_rank(&self) -> u8 {
+        match self {
+            Fabricant::Intel => 0,
+            Fabricant::Amd => 1,
+            Fabricant::Unknown => 3,
+            _ => 2,
+        }
+    }
+}
+
+impl Ord for Fabricant {
+    fn cmp(&self, other: &Self) -> Ordering {
+        fn folded(f: &Fabricant) -> impl Iterator<Item = u8> + '_ {
+            f.display_name().bytes().map(|b| b.to_ascii_lowercase())
+        }
+
+        self.display_rank()
+            .cmp(&other.display_rank())
+            .then_with(|| folded(self).cmp(folded(other)))
+            // Keep the order total: `Other("Apple")` sorts after `Apple`
+            .then_with(|| matches!(self, Fabricant::Other(_)).cmp(&matches!(other, Fabricant::Other(_))))
+            .then_with(|| self.display_name().cmp(other.display_name()))
+    }
+}
+
+impl PartialOrd for Fabricant {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 /// Individual CPU core information.
 ///
 /// Contains details about a single logical CPU core (thread).
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Core {
     /// Logical core ID (0-indexed)
     pub id: u32,
-    /// Core speed in MHz
+    /// Core speed in MHz (maximum frequency)
     pub speed_mhz: u32,
+    /// Maximum frequency in kHz as reported by the platform; read it through
+    /// [`Core::speed_khz`]. Not serialized, so a deserialized core reports
+    /// `speed_mhz * 1000`.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    speed_khz: u32,
+    /// Current core frequency in MHz (0 if unavailable)
+    pub current_mhz: u32,
+    /// Base (nominal, non-boost) frequency in MHz (0 if unavailable)
+    pub base_mhz: u32,
+    /// Minimum scaling frequency in MHz (0 if unavailable)
+    pub min_mhz: u32,
+    /// Maximum frequency the OS currently allows in MHz, e.g. cpufreq
+    /// `scaling_max_freq` (0 if unavailable)
+    pub scaling_max_mhz: u32,
+    /// Maximum frequency the hardware supports in MHz, e.g. cpufreq
+    /// `cpuinfo_max_freq` (0 if unavailable). Above `scaling_max_mhz` when
+    /// the firmware or a policy caps the clock.
+    pub hardware_max_mhz: u32,
     /// Physical core ID this logical core belongs to (for hyperthreading detection)
     pub physical_core_id: Option<u32>,
+    /// Core type on hybrid CPUs (P-core or E-core), `None` if not reported
+    pub core_type: Option<CoreType>,
+    /// Relative performance capacity reported by the kernel, `None` if not
+    /// reported. Only comparable between cores of the same machine: Linux
+    /// `cpu_capacity` scales the biggest core to 1024.
+    pub capacity: Option<u32>,
+    /// cpufreq scaling governor (e.g. `"performance"`), `None` if not reported
+    pub governor: Option<String>,
+    /// Microarchitecture name on ARM (e.g. `"Cortex-A78"`), `None` if unknown
+    pub part_name: Option<String>,
+    /// Logical cores sharing this core's physical core (SMT siblings),
+    /// including this one; empty if not reported
+    pub siblings: Vec<u32>,
+}
+
+/// Compares the maximum frequency through [`Core::speed_khz`], so the
+/// internal kHz value left behind when `speed_mhz` is changed doesn't make
+/// otherwise equal cores differ.
+///
+/// # Examples
+///
+/// ```
+/// use cpu_info::Core;
+///
+/// let mut core = Core::from_khz(0, 4_825_500, Some(0));
+/// core.speed_mhz = 3600;
+/// assert_eq!(core, Core::new(0, 3600, Some(0)));
+/// ```
+impl PartialEq for Core {
+    fn eq(&self, other: &Self) -> bool {
+        let Core {
+            id,
+            speed_mhz,
+            speed_khz: _,
+            current_mhz,
+            base_mhz,
+            min_mhz,
+            scaling_max_mhz,
+            hardware_max_mhz,
+            physical_core_id,
+            core_type,
+            capacity,
+            governor,
+            part_name,
+            siblings,
+        } = self;
+
+        *id == other.id
+            && *speed_mhz == other.speed_mhz
+            && self.speed_khz() == other.speed_khz()
+            && *current_mhz == other.current_mhz
+            && *base_mhz == other.base_mhz
+            && *min_mhz == other.min_mhz
+            && *scaling_max_mhz == other.scaling_max_mhz
+            && *hardware_max_mhz == other.hardware_max_mhz
+            && *physical_core_id == other.physical_core_id
+            && *core_type == other.core_type
+            && *capacity == other.capacity
+            && *governor == other.governor
+            && *part_name == other.part_name
+            && *siblings == other.siblings
+    }
+}
+
+impl Eq for Core {}
+
+/// Hashes the same values [`PartialEq`] compares.
+impl Hash for Core {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let Core {
+            id,
+            speed_mhz,
+            speed_khz: _,
+            current_mhz,
+            base_mhz,
+            min_mhz,
+            scaling_max_mhz,
+            hardware_max_mhz,
+            physical_core_id,
+            core_type,
+            capacity,
+            governor,
+            part_name,
+            siblings,
+        } = self;
+
+        id.hash(state);
+        speed_mhz.hash(state);
+        self.speed_khz().hash(state);
+        current_mhz.hash(state);
+        base_mhz.hash(state);
+        min_mhz.hash(state);
+        scaling_max_mhz.hash(state);
+        hardware_max_mhz.hash(state);
+        physical_core_id.hash(state);
+        core_type.hash(state);
+        capacity.hash(state);
+        governor.hash(state);
+        part_name.hash(state);
+        siblings.hash(state);
+    }
 }
 
 impl Core {
     /// Creates a new `Core` instance.
     ///
+    /// `current_mhz`, `base_mhz`, `min_mhz`, `scaling_max_mhz` and
+    /// `hardware_max_mhz` start at 0, `core_type`, `capacity`,
+    /// `governor` and `part_name` at `None` (unknown) and `siblings` empty.
+    ///
     /// # Arguments
     ///
     /// * `id` - Logical core ID
@@ -97,7 +1602,7 @@ impl Core {
     /// # Examples
     ///
     /// ```
-    /// use your_crate::Core;
+    /// use cpu_info::Core;
     ///
     /// let core = Core::new(0, 3600, Some(0));
     /// assert_eq!(core.id, 0);
@@ -107,9 +1612,106 @@ impl Core {
         Self {
             id,
             speed_mhz,
+            speed_khz: speed_mhz.saturating_mul(1000),
+            current_mhz: 0,
+            base_mhz: 0,
+            min_mhz: 0,
+            scaling_max_mhz: 0,
+            hardware_max_mhz: 0,
             physical_core_id,
+            core_type: None,
+            capacity: None,
+            governor: None,
+            part_name: None,
+            siblings: Vec::new(),
+        }
+    }
+
+    /// Creates a new `Core` from a maximum frequency in kHz.
+    ///
+    /// Like [`Core::new`], but keeps the exact value for [`Core::speed_khz`]
+    /// while `speed_mhz` is rounded down.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cpu_info::Core;
+    ///
+    /// let core = Core::from_khz(0, 4_825_500, Some(0));
+    /// assert_eq!(core.speed_mhz, 4825);
+    /// assert_eq!(core.speed_khz(), 4_825_500);
+    /// ```
+    pub fn from_khz(id: u32, speed_khz: u32, physical_core_id: Option<u32>) -> Self {
+        Self {
+            speed_khz,
+            ..Self::new(id, speed_khz / 1000, physical_core_id)
+        }
+    }
+
+    /// Returns the maximum core speed in kHz.
+    ///
+    /// This is the exact platform value (e.g. sysfs `cpuinfo_max_freq`) when
+    /// one was read. If `speed_mhz` has since been changed, or the platform
+    /// only reports MHz, it is `speed_mhz * 1000`.
+    pub fn speed_khz(&self) -> u32 {
+        if self.speed_khz / 1000 == self.speed_mhz {
+            self.speed_khz
+        } else {
+            self.speed_mhz.saturating_mul(1000)
         }
     }
+
+    /// Returns the maximum core speed in GHz.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cpu_info::Core;
+    ///
+    /// let core = Core::new(0, 3600, Some(0));
+    /// assert_eq!(format!("{:.2} GHz", core.speed_ghz()), "3.60 GHz");
+    /// ```
+    pub fn speed_ghz(&self) -> f64 {
+        self.speed_mhz as f64 / 1000.0
+    }
+
+    /// Returns `true` if this is a secondary SMT thread of its physical core.
+    ///
+    /// The first (lowest-numbered) logical core in `siblings` is the primary
+    /// thread; every other one is a hyperthread. `siblings` is filled during
+    /// detection, where all cores are visible, so this returns `None` when
+    /// it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cpu_info::Core;
+    ///
+    /// let mut primary = Core::new(0, 3600, Some(0));
+    /// primary.siblings = vec![0, 4];
+    /// let mut secondary = Core::new(4, 3600, Some(0));
+    /// secondary.siblings = vec![0, 4];
+    ///
+    /// assert_eq!(primary.is_hyperthread(), Some(false));
+    /// assert_eq!(secondary.is_hyperthread(), Some(true));
+    /// assert_eq!(Core::new(1, 3600, Some(1)).is_hyperthread(), None);
+    /// ```
+    pub fn is_hyperthread(&self) -> Option<bool> {
+        let primary = self.siblings.iter().min()?;
+        Some(self.id != *primary)
+    }
+}
+
+/// Type of a core on hybrid CPUs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CoreType {
+    /// Performance core (Intel P-core, ARM "big" core)
+    Performance,
+    /// Efficiency core (Intel E-core, ARM "LITTLE" core)
+    Efficient,
+    /// The platform reports a core type this crate doesn't recognize
+    Unknown,
 }
 
 /// CPU core distribution type.
@@ -117,7 +1719,24 @@ impl Core {
 /// Describes how CPU cores are organized in terms of frequency:
 /// - Traditional CPUs have all cores running at the same frequency (`Lineal`)
 /// - Hybrid CPUs have cores at different frequencies (`Hybrid`)
-#[derive(Debug, Clone)]
+///
+/// Cores reporting 0 MHz, typically offline ones whose cpufreq data is gone,
+/// don't make a CPU `Hybrid`:
+///
+/// ```
+/// use cpu_info::{CpuInfo, Core, DistributionCore};
+///
+/// let info = CpuInfo::builder()
+///     .add_core(Core::new(0, 4000, Some(0)))
+///     .add_core(Core::new(1, 4000, Some(1)))
+///     .add_core(Core::new(2, 4000, Some(2)))
+///     .add_core(Core::new(3, 0, Some(3)))
+///     .build();
+/// assert_eq!(info.distribution, DistributionCore::Lineal { mhz: 4000, base_mhz: 0 });
+/// assert_eq!(info.total_physical_cores, Some(4));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DistributionCore {
     /// All cores have the same frequency (traditional CPUs).
     ///
@@ -126,8 +1745,10 @@ pub enum DistributionCore {
     /// - AMD Ryzen 5 5600X (all 6 cores at same speed)
     /// - Intel Core i7-9700K (all 8 cores at same speed)
     Lineal {
-        /// Base frequency in MHz
+        /// Maximum (boost) frequency in MHz
         mhz: u32,
+        /// Base (nominal) frequency in MHz, 0 if unavailable
+        base_mhz: u32,
     },
     /// Cores have different frequencies (hybrid architecture).
     ///
@@ -137,7 +1758,651 @@ pub enum DistributionCore {
     /// - Some ARM big.LITTLE configurations
     /// - AMD CPUs with boost-per-core variations
     Hybrid {
-        /// Vector of all cores with individual frequencies
+        /// Vector of all cores with individual frequencies, in ascending id order
         groups: Vec<Core>,
     },
-}
\ No newline at end of file
+}
+
+/// Hashes `Hybrid` cores in id order, so the same CPU hashes equally no
+/// matter how the platform enumerated its cores.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::hash_map::DefaultHasher;
+/// use std::hash::{Hash, Hasher};
+/// use cpu_info::{Core, DistributionCore};
+///
+/// let hash = |d: &DistributionCore| {
+///     let mut hasher = DefaultHasher::new();
+///     d.hash(&mut hasher);
+///     hasher.finish()
+/// };
+///
+/// let p = Core::new(0, 5000, Some(0));
+/// let e = Core::new(1, 3800, Some(1));
+/// let a = DistributionCore::Hybrid { groups: vec![p.clone(), e.clone()] };
+/// let b = DistributionCore::Hybrid { groups: vec![e, p] };
+/// assert_eq!(hash(&a), hash(&b));
+/// ```
+impl Hash for DistributionCore {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        core::mem::discriminant(self).hash(state);
+        match self {
+            DistributionCore::Lineal { mhz, base_mhz } => {
+                mhz.hash(state);
+                base_mhz.hash(state);
+            }
+            DistributionCore::Hybrid { groups } => {
+                let mut sorted: Vec<&Core> = groups.iter().collect();
+                sorted.sort_by_key(|core| core.id);
+                sorted.hash(state);
+            }
+        }
+    }
+}
+
+impl DistributionCore {
+    /// Largest spread, in MHz, between core maximum frequencies that still
+    /// counts as a uniform (`Lineal`) CPU.
+    ///
+    /// Boost binning makes otherwise identical cores differ by a few MHz on
+    /// some AMD parts; real hybrid designs are hundreds of MHz apart.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cpu_info::{Core, CpuInfo};
+    ///
+    /// let binned = CpuInfo::builder()
+    ///     .add_core(Core::new(0, 4200, Some(0)))
+    ///     .add_core(Core::new(1, 4225, Some(1)))
+    ///     .build();
+    /// assert!(!binned.is_hybrid());
+    ///
+    /// let hybrid = CpuInfo::builder()
+    ///     .add_core(Core::new(0, 4200, Some(0)))
+    ///     .add_core(Core::new(1, 3600, Some(1)))
+    ///     .build();
+    /// assert!(hybrid.is_hybrid());
+    /// ```
+    pub const FREQUENCY_TOLERANCE_MHZ: u32 = 50;
+
+    /// Largest spread, in percent of the biggest core, between core
+    /// capacities that still counts as a uniform (`Lineal`) CPU.
+    ///
+    /// Capacity replaces frequency for the classification when every core
+    /// reports one, since asymmetric cores can share the same clock. Favored
+    /// cores on some non-hybrid parts report a slightly higher capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cpu_info::{Core, CpuInfo};
+    ///
+    /// let mut big = Core::new(0, 2400, Some(0));
+    /// big.capacity = Some(1024);
+    /// let mut little = Core::new(1, 2400, Some(1));
+    /// little.capacity = Some(446);
+    ///
+    /// let info = CpuInfo::builder().add_core(big).add_core(little).build();
+    /// assert!(info.is_hybrid());
+    /// ```
+    pub const CAPACITY_TOLERANCE_PERCENT: u32 = 10;
+
+    /// Returns the shared maximum frequency in GHz for `Lineal` CPUs.
+    ///
+    /// `Hybrid` CPUs have no single frequency and return `None`; use
+    /// [`Core::speed_ghz`] on their cores instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cpu_info::DistributionCore;
+    ///
+    /// let distribution = DistributionCore::Lineal { mhz: 4900, base_mhz: 3600 };
+    /// assert_eq!(distribution.speed_ghz(), Some(4.9));
+    /// ```
+    pub fn speed_ghz(&self) -> Option<f64> {
+        match self {
+            DistributionCore::Lineal { mhz, .. } => Some(*mhz as f64 / 1000.0),
+            DistributionCore::Hybrid { .. } => None,
+        }
+    }
+
+    /// Buckets `Hybrid` cores by maximum frequency as `(mhz, core_ids)`.
+    ///
+    /// Groups are ordered fastest first and ids ascending within a group.
+    /// `Lineal` CPUs don't keep a per-core list and return an empty vector;
+    /// use [`CpuInfo::cores`] to enumerate them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cpu_info::{Core, DistributionCore};
+    ///
+    /// let distribution = DistributionCore::Hybrid {
+    ///     groups: vec![
+    ///         Core::new(2, 3600, Some(2)),
+    ///         Core::new(0, 4800, Some(0)),
+    ///         Core::new(1, 4800, Some(1)),
+    ///     ],
+    /// };
+    /// assert_eq!(
+    ///     distribution.frequency_groups(),
+    ///     vec![(4800, vec![0, 1]), (3600, vec![2])]
+    /// );
+    /// ```
+    pub fn frequency_groups(&self) -> Vec<(u32, Vec<u32>)> {
+        let DistributionCore::Hybrid { groups } = self else {
+            return Vec::new();
+        };
+
+        let mut tiers: BTreeMap<u32, Vec<u32>> = BTreeMap::new();
+        for core in groups {
+            tiers.entry(core.speed_mhz).or_default().push(core.id);
+        }
+
+        tiers
+            .into_iter()
+            .rev()
+            .map(|(mhz, mut ids)| {
+                ids.sort_unstable();
+                (mhz, ids)
+            })
+            .collect()
+    }
+
+    /// Returns a single frequency in MHz that represents the whole CPU.
+    ///
+    /// `Lineal` CPUs return their shared `mhz`. `Hybrid` CPUs return the most
+    /// common core maximum frequency, preferring the higher one on a tie, so
+    /// a part with 8 P-cores and 16 E-cores reports the E-core clock. Cores
+    /// at 0 MHz (usually offline) are skipped; 0 is returned when no core
+    /// has a frequency.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cpu_info::{Core, DistributionCore};
+    ///
+    /// let distribution = DistributionCore::Hybrid {
+    ///     groups: vec![
+    ///         Core::new(0, 4800, Some(0)),
+    ///         Core::new(1, 4800, Some(1)),
+    ///         Core::new(2, 3600, Some(2)),
+    ///         Core::new(3, 3600, Some(3)),
+    ///         Core::new(4, 3600, Some(4)),
+    ///     ],
+    /// };
+    /// assert_eq!(distribution.representative_mhz(), 3600);
+    ///
+    /// // Two at each frequency: the higher one wins
+    /// let tie = DistributionCore::Hybrid {
+    ///     groups: vec![
+    ///         Core::new(0, 4800, Some(0)),
+    ///         Core::new(1, 4800, Some(1)),
+    ///         Core::new(2, 3600, Some(2)),
+    ///         Core::new(3, 3600, Some(3)),
+    ///     ],
+    /// };
+    /// assert_eq!(tie.representative_mhz(), 4800);
+    ///
+    /// let lineal = DistributionCore::Lineal { mhz: 4200, base_mhz: 3600 };
+    /// assert_eq!(lineal.representative_mhz(), 4200);
+    /// ```
+    pub fn representative_mhz(&self) -> u32 {
+        let groups = match self {
+            DistributionCore::Lineal { mhz, .. } => return *mhz,
+            DistributionCore::Hybrid { groups } => groups,
+        };
+
+        let mut counts: BTreeMap<u32, usize> = BTreeMap::new();
+        for core in groups.iter().filter(|core| core.speed_mhz > 0) {
+            *counts.entry(core.speed_mhz).or_default() += 1;
+        }
+
+        counts
+            .into_iter()
+            .max_by_key(|&(mhz, count)| (count, mhz))
+            .map_or(0, |(mhz, _)| mhz)
+    }
+}
+
+/// Address widths supported by the CPU, in bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AddressBits {
+    /// Physical address width (e.g. 39 or 46)
+    pub physical: u8,
+    /// Linear (virtual) address width (e.g. 48, or 57 with 5-level paging)
+    pub virtual_: u8,
+}
+
+/// Raw register values returned by one CPUID query.
+///
+/// Returned by `CpuInfo::cpuid`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CpuidResult {
+    /// EAX register
+    pub eax: u32,
+    /// EBX register
+    pub ebx: u32,
+    /// ECX register
+    pub ecx: u32,
+    /// EDX register
+    pub edx: u32,
+}
+
+/// x86 processor signature (family, model and stepping).
+///
+/// These are the numbers used by vendors in errata and microcode lists,
+/// already combined with the extended family/model fields.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Signature {
+    /// Processor family (base family plus extended family)
+    pub family: u16,
+    /// Processor model (including the extended model bits)
+    pub model: u16,
+    /// Processor stepping
+    pub stepping: u8,
+}
+
+impl Signature {
+    /// Decodes a signature from the EAX value of CPUID leaf 1.
+    ///
+    /// The extended family is only added for family 0xF, and the extended
+    /// model only applies to families 0x6 and 0xF.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cpu_info::Signature;
+    ///
+    /// // Intel Core i7-8700K (Coffee Lake)
+    /// let signature = Signature::from_eax(0x000906EA);
+    /// assert_eq!(signature.family, 6);
+    /// assert_eq!(signature.model, 158);
+    /// assert_eq!(signature.stepping, 10);
+    /// ```
+    pub fn from_eax(eax: u32) -> Self {
+        let stepping = (eax & 0xF) as u8;
+        let base_model = ((eax >> 4) & 0xF) as u16;
+        let base_family = ((eax >> 8) & 0xF) as u16;
+        let ext_model = ((eax >> 16) & 0xF) as u16;
+        let ext_family = ((eax >> 20) & 0xFF) as u16;
+
+        let family = if base_family == 0xF {
+            base_family + ext_family
+        } else {
+            base_family
+        };
+
+        let model = if base_family == 0x6 || base_family == 0xF {
+            (ext_model << 4) + base_model
+        } else {
+            base_model
+        };
+
+        Self {
+            family,
+            model,
+            stepping,
+        }
+    }
+}
+
+/// Hypervisor detected through CPUID.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Hypervisor {
+    /// Linux Kernel-based Virtual Machine
+    KVM,
+    /// VMware
+    VMware,
+    /// Microsoft Hyper-V (also used by WSL2 and Azure)
+    HyperV,
+    /// Xen
+    Xen,
+    /// QEMU without hardware acceleration (TCG)
+    QEMU,
+    /// Other hypervisor with its vendor string
+    Other(String),
+    /// Running on bare metal
+    None,
+}
+
+/// Instruction-set extension that can be detected at runtime.
+///
+/// x86 features are read with CPUID; AVX-family features are only reported
+/// when the operating system also saves the extended register state.
+///
+/// Features are ordered by declaration, which is the canonical order used by
+/// [`CpuInfo::features_string`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CpuFeature {
+    /// Streaming SIMD Extensions (x86)
+    Sse,
+    /// SSE2 (x86)
+    Sse2,
+    /// SSE3 (x86)
+    Sse3,
+    /// Supplemental SSE3 (x86)
+    Ssse3,
+    /// SSE4.1 (x86)
+    Sse4_1,
+    /// SSE4.2 (x86)
+    Sse4_2,
+    /// Population count instruction (x86)
+    Popcnt,
+    /// AES-NI encryption instructions (x86)
+    Aes,
+    /// Advanced Vector Extensions (x86)
+    Avx,
+    /// AVX2 (x86)
+    Avx2,
+    /// Fused multiply-add, FMA3 (x86)
+    Fma,
+    /// Bit Manipulation Instruction Set 1 (x86)
+    Bmi1,
+    /// Bit Manipulation Instruction Set 2 (x86)
+    Bmi2,
+    /// AVX-512 Foundation (x86)
+    Avx512F,
+    /// AVX-512 Doubleword and Quadword Instructions (x86)
+    Avx512Dq,
+    /// AVX-512 Byte and Word Instructions (x86)
+    Avx512Bw,
+    /// AVX-512 Vector Length Extensions (x86)
+    Avx512Vl,
+    /// AVX-512 Vector Byte Manipulation Instructions (x86)
+    Avx512Vbmi,
+    /// AVX-512 Vector Neural Network Instructions (x86)
+    Avx512Vnni,
+    /// AVX-512 BFloat16 Instructions (x86)
+    Avx512Bf16,
+    /// Hardware random number generator instruction (x86)
+    Rdrand,
+    /// Hardware entropy source for seeding, RDSEED (x86)
+    Rdseed,
+    /// Advanced SIMD (ARM)
+    Neon,
+    /// Scalable Vector Extension (ARM)
+    Sve,
+}
+
+impl fmt::Display for CpuFeature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            CpuFeature::Sse => "sse",
+            CpuFeature::Sse2 => "sse2",
+            CpuFeature::Sse3 => "sse3",
+            CpuFeature::Ssse3 => "ssse3",
+            CpuFeature::Sse4_1 => "sse4.1",
+            CpuFeature::Sse4_2 => "sse4.2",
+            CpuFeature::Popcnt => "popcnt",
+            CpuFeature::Aes => "aes",
+            CpuFeature::Avx => "avx",
+            CpuFeature::Avx2 => "avx2",
+            CpuFeature::Fma => "fma",
+            CpuFeature::Bmi1 => "bmi1",
+            CpuFeature::Bmi2 => "bmi2",
+            CpuFeature::Avx512F => "avx512f",
+            CpuFeature::Avx512Dq => "avx512dq",
+            CpuFeature::Avx512Bw => "avx512bw",
+            CpuFeature::Avx512Vl => "avx512vl",
+            CpuFeature::Avx512Vbmi => "avx512vbmi",
+            CpuFeature::Avx512Vnni => "avx512vnni",
+            CpuFeature::Avx512Bf16 => "avx512bf16",
+            CpuFeature::Rdrand => "rdrand",
+            CpuFeature::Rdseed => "rdseed",
+            CpuFeature::Neon => "neon",
+            CpuFeature::Sve => "sve",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// A NUMA node and the logical CPUs attached to it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NumaNode {
+    /// NUMA node ID
+    pub id: u32,
+    /// Logical core IDs belonging to this node
+    pub cpus: Vec<u32>,
+}
+
+/// A group of logical CPUs sharing a microarchitecture, such as one side of
+/// an ARM big.LITTLE design.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CpuCluster {
+    /// Cluster ID
+    pub id: u32,
+    /// Logical core IDs belonging to this cluster
+    pub cpus: Vec<u32>,
+    /// Highest maximum frequency among the cluster's cores in MHz (0 if unknown)
+    pub max_mhz: u32,
+}
+
+/// Flat overview of a [`CpuInfo`], returned by [`CpuInfo::summary`].
+///
+/// Meant for tables and dashboards, so every field is a plain value.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CpuSummary {
+    /// CPU model name
+    pub model: String,
+    /// Architecture name, as printed by `CpuArchitecture`'s `Display`
+    pub arch: String,
+    /// Physical core count (0 if unknown)
+    pub physical: usize,
+    /// Logical core count (0 if unknown)
+    pub logical: usize,
+    /// Highest maximum core frequency in MHz (0 if unknown)
+    pub max_mhz: u32,
+    /// Whether cores run at different frequencies
+    pub hybrid: bool,
+}
+
+/// One field that differs between two snapshots, returned by [`CpuInfo::diff`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CpuInfoChange {
+    /// Name of the compared value (e.g. `"model"` or `"max_frequency_mhz"`)
+    pub field: String,
+    /// Value in the first snapshot, `Debug`-formatted
+    pub old: String,
+    /// Value in the second snapshot, `Debug`-formatted
+    pub new: String,
+}
+
+impl CpuInfoChange {
+    /// Records a change for `field` if `old` and `new` differ.
+    fn compare<T: PartialEq + fmt::Debug>(changes: &mut Vec<Self>, field: &str, old: &T, new: &T) {
+        if old != new {
+            changes.push(CpuInfoChange {
+                field: field.to_string(),
+                old: format!("{:?}", old),
+                new: format!("{:?}", new),
+            });
+        }
+    }
+}
+
+/// Kernel-reported status of a known CPU vulnerability.
+///
+/// Returned by `CpuInfo::vulnerabilities` on Linux.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vulnerability {
+    /// Vulnerability name as used by the kernel (e.g. `"spectre_v2"`)
+    pub name: String,
+    /// Status line, verbatim (e.g. `"Mitigation: PTI"` or `"Not affected"`)
+    pub status: String,
+}
+
+/// A CPU idle state (C-state) as described by the kernel's cpuidle driver.
+///
+/// Returned by `CpuInfo::idle_states` on Linux.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IdleState {
+    /// State name (e.g. `"C1E"` or `"C6"`)
+    pub name: String,
+    /// Exit latency in microseconds
+    pub latency_us: u32,
+    /// Minimum time in microseconds the CPU should stay idle for the state
+    /// to save power
+    pub residency_us: u32,
+}
+
+/// Runtime SMT (Hyper-Threading) control state.
+///
+/// Returned by `CpuInfo::smt_control` on Linux. Only `On` and `Off` can be
+/// changed without a reboot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SmtControl {
+    /// SMT is enabled and can be switched off
+    On,
+    /// SMT was disabled at runtime and can be switched back on
+    Off,
+    /// SMT was disabled on the kernel command line (`nosmt=force`)
+    ForceOff,
+    /// The CPU has no SMT
+    NotSupported,
+    /// The architecture doesn't implement SMT control
+    NotImplemented,
+}
+
+/// Type of data held by a CPU cache.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CacheKind {
+    /// Data cache (e.g. L1d)
+    Data,
+    /// Instruction cache (e.g. L1i)
+    Instruction,
+    /// Unified data and instruction cache (usually L2/L3)
+    Unified,
+}
+
+/// Information about a single CPU cache.
+///
+/// Caches shared between cores (such as L3) are reported once, with
+/// `shared_by` holding the number of logical cores that use them.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CacheInfo {
+    /// Cache level (1 for L1, 2 for L2, ...)
+    pub level: u8,
+    /// Type of data the cache holds
+    pub kind: CacheKind,
+    /// Cache size in bytes
+    pub size_bytes: u64,
+    /// Coherency line size in bytes
+    pub line_size: u32,
+    /// Number of logical cores sharing this cache
+    pub shared_by: usize,
+    /// Logical core IDs sharing this cache; empty if only the count is
+    /// known, as with CPUID
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub shared_cpu_ids: Vec<u32>,
+}
+
+impl CacheInfo {
+    /// Decodes one subleaf of CPUID leaf 4 (deterministic cache parameters).
+    ///
+    /// AMD's leaf 0x8000001D uses the same layout. Returns `None` for the
+    /// null entry (type 0) that terminates the subleaf list. `shared_by` is
+    /// the maximum number of logical cores that may share the cache, as
+    /// reported in EAX bits 25:14.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cpu_info::{CacheInfo, CacheKind};
+    ///
+    /// // 32 KiB, 8-way L1 data cache with 64-byte lines, shared by 2 threads
+    /// let cache = CacheInfo::from_cpuid(0x1C004121, 0x01C0003F, 0x0000003F).unwrap();
+    /// assert_eq!(cache.level, 1);
+    /// assert_eq!(cache.kind, CacheKind::Data);
+    /// assert_eq!(cache.size_bytes, 32 * 1024);
+    /// assert_eq!(cache.line_size, 64);
+    /// assert_eq!(cache.shared_by, 2);
+    ///
+    /// assert_eq!(CacheInfo::from_cpuid(0, 0, 0), None);
+    /// ```
+    pub fn from_cpuid(eax: u32, ebx: u32, ecx: u32) -> Option<Self> {
+        let kind = match eax & 0x1F {
+            1 => CacheKind::Data,
+            2 => CacheKind::Instruction,
+            3 => CacheKind::Unified,
+            _ => return None,
+        };
+
+        let ways = u64::from((ebx >> 22) & 0x3FF) + 1;
+        let partitions = u64::from((ebx >> 12) & 0x3FF) + 1;
+        let line_size = (ebx & 0xFFF) + 1;
+        let sets = u64::from(ecx) + 1;
+
+        Some(Self {
+            level: ((eax >> 5) & 0x7) as u8,
+            kind,
+            size_bytes: ways * partitions * u64::from(line_size) * sets,
+            line_size,
+            shared_by: ((eax >> 14) & 0xFFF) as usize + 1,
+            shared_cpu_ids: Vec::new(),
+        })
+    }
+}
+
+/// Errors that can occur while detecting CPU information.
+///
+/// Returned by `CpuInfo::try_new`. `CpuInfo::new` never fails and falls back
+/// to placeholder values (`Unknown`, `None`, `0`) instead. Only available
+/// with the `std` feature.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub enum CpuInfoError {
+    /// `/sys/devices/system/cpu` is missing or unreadable
+    SysfsUnavailable(io::Error),
+    /// `/proc/cpuinfo` is missing or unreadable (e.g. procfs not mounted)
+    ProcfsUnavailable(io::Error),
+    /// The CPU does not support the required CPUID leaf
+    CpuidUnsupported,
+    /// A value was read but could not be parsed
+    ParseError(String),
+    /// A platform query (`sysctl`, Win32 API, registry) failed
+    Os(io::Error),
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for CpuInfoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CpuInfoError::SysfsUnavailable(e) => write!(f, "sysfs unavailable: {}", e),
+            CpuInfoError::ProcfsUnavailable(e) => write!(f, "procfs unavailable: {}", e),
+            CpuInfoError::CpuidUnsupported => write!(f, "CPUID leaf not supported"),
+            CpuInfoError::ParseError(msg) => write!(f, "parse error: {}", msg),
+            CpuInfoError::Os(e) => write!(f, "system query failed: {}", e),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CpuInfoError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CpuInfoError::SysfsUnavailable(e)
+            | CpuInfoError::ProcfsUnavailable(e)
+            | CpuInfoError::Os(e) => Some(e),
+            _ => None,
+        }
+    }
+}
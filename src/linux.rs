@@ -1,7 +1,47 @@
+use std::ffi::OsStr;
 use std::fs;
-use std::io;
-use std::collections::HashSet;
-use crate::{Core,CpuArchitecture,CpuInfo,Fabricant,DistributionCore};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::path::{Path, PathBuf};
+use crate::{AddressBits,CacheInfo,CacheKind,Core,CoreType,CpuCluster,CpuFeature,CpuInfo,CpuInfoError,DistributionCore,Fabricant,IdleState,NumaNode,SmtControl,Vulnerability};
+
+/// Default location of the per-CPU sysfs directories.
+const SYSFS_CPU_ROOT: &str = "/sys/devices/system/cpu";
+
+/// Frequency settings shared by every CPU of one cpufreq policy.
+#[derive(Clone, Default)]
+struct CpufreqPolicy {
+    hardware_max_khz: Option<u32>,
+    scaling_max_khz: Option<u32>,
+    min_khz: u32,
+    base_khz: u32,
+    current_khz: u32,
+    governor: Option<String>,
+}
+
+impl CpufreqPolicy {
+    /// Reads a cpufreq directory, either `cpufreq/policyN` or `cpuN/cpufreq`.
+    ///
+    /// Missing files are left at zero/`None`.
+    fn read(dir: &Path) -> Self {
+        let read_khz = |name: &str| {
+            fs::read_to_string(dir.join(name))
+                .ok()
+                .and_then(|s| s.trim().parse::<u32>().ok())
+        };
+
+        CpufreqPolicy {
+            hardware_max_khz: read_khz("cpuinfo_max_freq"),
+            scaling_max_khz: read_khz("scaling_max_freq"),
+            min_khz: read_khz("cpuinfo_min_freq").unwrap_or(0),
+            // Only exposed by some drivers (e.g. intel_pstate)
+            base_khz: read_khz("base_frequency").unwrap_or(0),
+            current_khz: read_khz("scaling_cur_freq").unwrap_or(0),
+            governor: fs::read_to_string(dir.join("scaling_governor"))
+                .ok()
+                .map(|s| s.trim().to_string()),
+        }
+    }
+}
 
 #[cfg(feature = "linux")]
 impl CpuInfo {
@@ -13,11 +53,11 @@ impl CpuInfo {
     /// # Examples
     ///
     /// ```no_run
-    /// use your_crate::CpuInfo;
+    /// use cpu_info::{CpuInfo, DistributionCore};
     ///
     /// let cpu_info = CpuInfo::new();
     /// match cpu_info.distribution {
-    ///     DistributionCore::Lineal { mhz } => {
+    ///     DistributionCore::Lineal { mhz, .. } => {
     ///         println!("Uniform CPU with all cores at {} MHz", mhz);
     ///     }
     ///     DistributionCore::Hybrid { ref groups } => {
@@ -26,334 +66,2015 @@ impl CpuInfo {
     /// }
     /// ```
     pub fn new() -> Self {
+        if let Some(Ok(info)) = Self::from_override() {
+            return info;
+        }
+
+        Self::from_sysfs_root(Path::new(SYSFS_CPU_ROOT))
+    }
+
+    /// Detects CPU information, reading the per-CPU directories from `root`.
+    ///
+    /// `new()` passes the real `/sys/devices/system/cpu`; tests can point it
+    /// at a fixture tree of fake `cpuN/` entries instead.
+    fn from_sysfs_root(root: &Path) -> Self {
         // Count ALL cores first (independent of cpufreq availability)
-        let total_logical_cores = Self::get_total_logical_cores();
-        
+        let total_logical_cores = Self::get_total_logical_cores(root).ok().flatten();
+
+        Self::from_sysfs_parts(
+            root,
+            total_logical_cores,
+            Self::get_fabricant().unwrap_or(Fabricant::Unknown),
+            Self::get_model().ok().flatten(),
+        )
+    }
+
+    /// Like [`CpuInfo::from_sysfs_root`], but fails when a data source
+    /// cannot be read at all; used by `try_new()`.
+    fn try_from_sysfs_root(root: &Path) -> Result<Self, CpuInfoError> {
+        let total_logical_cores = Self::get_total_logical_cores(root)?;
+        let fabricant = Self::get_fabricant()?;
+        let model = Self::get_model()?;
+
+        Ok(Self::from_sysfs_parts(root, total_logical_cores, fabricant, model))
+    }
+
+    /// Reads everything below `root` and assembles the `CpuInfo` around the
+    /// values whose errors the two constructors handle differently.
+    fn from_sysfs_parts(
+        root: &Path,
+        total_logical_cores: Option<usize>,
+        fabricant: Fabricant,
+        model: Option<String>,
+    ) -> Self {
         // Get detailed core information (may be partial on some systems)
-        let cores = Self::get_cores();
-
-        // Derive physical cores from detailed info or use fallback
-        let total_physical_cores = if !cores.is_empty() {
-            let mut physical_ids = HashSet::new();
-            for core in &cores {
-                if let Some(id) = core.physical_core_id {
-                    physical_ids.insert(id);
-                }
-            }
-            if physical_ids.is_empty() {
-                Self::get_total_physical_cores_fallback()
-            } else {
-                Some(physical_ids.len())
-            }
-        } else {
-            Self::get_total_physical_cores_fallback()
-        };
+        let cores = Self::get_cores(root);
 
+        let total_physical_cores = Self::get_total_physical_cores(root, &cores);
         let distribution = Self::detect_distribution(&cores);
 
         Self {
             architecture: Self::get_architecture(),
-            fabricant: Self::get_fabricant().unwrap_or(Fabricant::Unknown),
-            model: Self::get_model().unwrap_or("Unknown".to_string()),
+            endianness: Self::get_endianness(),
+            fabricant,
+            model: model.unwrap_or("Unknown".to_string()),
             total_logical_cores,
+            online_logical_cores: Self::get_online_logical_cores(root),
             total_physical_cores,
+            total_sockets: Self::get_total_sockets(root),
+            smt_enabled: Self::get_smt_enabled(root, total_logical_cores, total_physical_cores),
             distribution,
+            caches: Self::get_caches_linux(root),
+            features: Self::get_features(),
+            signature: Self::get_signature(),
+            microcode: Self::get_microcode(root),
+            address_bits: Self::get_address_bits().or_else(Self::get_address_bits_procfs),
+            hypervisor: Self::get_hypervisor(),
+            numa_nodes: Self::get_numa_nodes(root),
+            clusters: Self::get_clusters(root, &cores),
         }
     }
 
-    /// Detects the CPU architecture using Rust's built-in constants.
+    /// Creates a new `CpuInfo` instance, reporting why detection failed.
     ///
-    /// This method is compile-time safe and doesn't require any system calls.
-    fn get_architecture() -> CpuArchitecture {
-        match std::env::consts::ARCH {
-            "x86_64" => CpuArchitecture::X86_64,
-            "aarch64" => CpuArchitecture::ARM64,
-            "arm" => CpuArchitecture::ARM,
-            "x86" => CpuArchitecture::X86,
-            _ => CpuArchitecture::Unknown,
-        }
-    }
-
-    /// Detects the CPU manufacturer/vendor.
+    /// Unlike [`CpuInfo::new`], which falls back to placeholder values, this
+    /// returns an error when a data source cannot be read at all (e.g. sysfs
+    /// or procfs not mounted). Values the system genuinely does not report
+    /// still come back as `Fabricant::Unknown`, `"Unknown"` or `None`.
     ///
-    /// On x86/x86_64, this uses the CPUID instruction for language-independent detection.
-    /// On ARM and other architectures, it reads from `/proc/cpuinfo`.
-    fn get_fabricant() -> Result<Fabricant, io::Error> {
-        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-        {
-            return Self::get_fabricant_cpuid();
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cpu_info::{CpuInfo, CpuInfoError};
+    ///
+    /// match CpuInfo::try_new() {
+    ///     Ok(info) => println!("{:?}", info.fabricant),
+    ///     Err(CpuInfoError::ProcfsUnavailable(e)) => eprintln!("procfs not mounted: {}", e),
+    ///     Err(e) => eprintln!("detection failed: {}", e),
+    /// }
+    /// ```
+    pub fn try_new() -> Result<Self, CpuInfoError> {
+        if let Some(result) = Self::from_override() {
+            return result;
         }
 
-        #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
-        {
-            Self::get_fabricant_arm()
-        }
+        Self::try_from_sysfs_root(Path::new(SYSFS_CPU_ROOT))
     }
 
-    /// Uses CPUID instruction to detect CPU vendor on x86/x86_64.
+    /// Re-reads the current frequency of every known core.
     ///
-    /// This method is language-independent and works regardless of system locale.
-    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-    fn get_fabricant_cpuid() -> Result<Fabricant, io::Error> {
-        #[cfg(target_arch = "x86")]
-        use core::arch::x86::__cpuid;
-        #[cfg(target_arch = "x86_64")]
-        use core::arch::x86_64::__cpuid;
-
-        unsafe {
-            let result = __cpuid(0);
-            
-            // EBX, EDX, ECX contain the vendor string (12 bytes)
-            let mut vendor = [0u8; 12];
-            vendor[0..4].copy_from_slice(&result.ebx.to_le_bytes());
-            vendor[4..8].copy_from_slice(&result.edx.to_le_bytes());
-            vendor[8..12].copy_from_slice(&result.ecx.to_le_bytes());
-            
-            Ok(match &vendor {
-                b"GenuineIntel" => Fabricant::Intel,
-                b"AuthenticAMD" => Fabricant::Amd,
-                _ => Fabricant::Other(String::from_utf8_lossy(&vendor).trim().to_string()),
-            })
-        }
-    }
-
-    /// Detects CPU manufacturer on ARM by reading the implementer ID.
+    /// Only `cpufreq/scaling_cur_freq` is read again; topology, vendor, model
+    /// and the other static fields are left untouched, so this is much cheaper
+    /// than calling [`CpuInfo::new`] in a loop. It is a no-op for
+    /// `DistributionCore::Lineal`, which doesn't track current frequencies.
     ///
-    /// This method parses hexadecimal implementer IDs and maps them to known vendors.
-    #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
-    fn get_fabricant_arm() -> Result<Fabricant, io::Error> {
-        let content = fs::read_to_string("/proc/cpuinfo")?;
-        
-        for line in content.lines() {
-            if let Some(colon_pos) = line.find(':') {
-                let key = line[..colon_pos].trim().to_lowercase();
-                if key.contains("implementer") {
-                    let value = line[colon_pos + 1..].trim();
-                    
-                    // Parse the value (can be "0x41" or "41")
-                    let implementer = if let Some(hex_str) = value.strip_prefix("0x") {
-                        u32::from_str_radix(hex_str, 16).ok()
-                    } else {
-                        u32::from_str_radix(value, 16).ok()
-                    };
-                    
-                    return Ok(match implementer {
-                        Some(0x41) => Fabricant::Other("ARM".to_string()),
-                        Some(0x42) => Fabricant::Other("Broadcom".to_string()),
-                        Some(0x43) => Fabricant::Other("Cavium".to_string()),
-                        Some(0x44) => Fabricant::Other("DEC".to_string()),
-                        Some(0x4e) => Fabricant::Other("Nvidia".to_string()),
-                        Some(0x50) => Fabricant::Other("APM".to_string()),
-                        Some(0x51) => Fabricant::Qualcomm,
-                        Some(0x56) => Fabricant::Other("Marvell".to_string()),
-                        Some(0x61) => Fabricant::Other("Apple".to_string()),
-                        _ => Fabricant::Other(value.to_string()),
-                    });
-                }
-            }
-        }
-        
-        Ok(Fabricant::Unknown)
-    }
-
-    /// Detects the CPU model name.
+    /// # Examples
     ///
-    /// On x86/x86_64, this uses CPUID for reliable detection.
-    /// Falls back to reading `/proc/cpuinfo` if CPUID is unavailable.
-    fn get_model() -> Result<String, io::Error> {
-        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-        {
-            if let Ok(model) = Self::get_model_cpuid() {
-                return Ok(model);
-            }
-        }
-
-        Self::get_model_procfs()
-    }
-
-    /// Uses CPUID extended functions to get the CPU brand string on x86/x86_64.
-    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-    fn get_model_cpuid() -> Result<String, io::Error> {
-        #[cfg(target_arch = "x86")]
-        use core::arch::x86::__cpuid;
-        #[cfg(target_arch = "x86_64")]
-        use core::arch::x86_64::__cpuid;
-
-        unsafe {
-            let ext_result = __cpuid(0x80000000);
-            if ext_result.eax < 0x80000004 {
-                return Err(io::Error::new(io::ErrorKind::NotFound, "Extended CPUID not supported"));
-            }
+    /// ```no_run
+    /// use cpu_info::CpuInfo;
+    ///
+    /// let mut cpu_info = CpuInfo::new();
+    /// cpu_info.refresh_frequencies();
+    /// for core in cpu_info.cores() {
+    ///     println!("cpu{}: {} MHz", core.id, core.current_mhz);
+    /// }
+    /// ```
+    pub fn refresh_frequencies(&mut self) {
+        let root = Path::new(SYSFS_CPU_ROOT);
 
-            let mut brand = [0u8; 48];
-            
-            // Read the 3 registers containing the brand string
-            for i in 0..3 {
-                let result = __cpuid(0x80000002 + i);
-                let offset = i as usize * 16;
-                brand[offset..offset + 4].copy_from_slice(&result.eax.to_le_bytes());
-                brand[offset + 4..offset + 8].copy_from_slice(&result.ebx.to_le_bytes());
-                brand[offset + 8..offset + 12].copy_from_slice(&result.ecx.to_le_bytes());
-                brand[offset + 12..offset + 16].copy_from_slice(&result.edx.to_le_bytes());
-            }
-            
-            let model = String::from_utf8_lossy(&brand).trim().to_string();
-            
-            if model.is_empty() {
-                Err(io::Error::new(io::ErrorKind::NotFound, "Model not found"))
-            } else {
-                Ok(model)
+        if let DistributionCore::Hybrid { groups } = &mut self.distribution {
+            for core in groups {
+                core.current_mhz = fs::read_to_string(
+                    root.join(format!("cpu{}/cpufreq/scaling_cur_freq", core.id)),
+                )
+                .ok()
+                .and_then(|s| s.trim().parse::<u32>().ok())
+                .map_or(0, |khz| khz / 1000);
             }
         }
     }
 
-    /// Reads the CPU model name from `/proc/cpuinfo`.
+    /// Re-reads how many logical cores are online.
     ///
-    /// This method uses case-insensitive comparison to handle different locales.
-    fn get_model_procfs() -> Result<String, io::Error> {
-        let content = fs::read_to_string("/proc/cpuinfo")?;
-        
-        for line in content.lines() {
-            if let Some(colon_pos) = line.find(':') {
-                let key = line[..colon_pos].trim();
-                // Search for "model name" case-insensitive
-                if key.eq_ignore_ascii_case("model name") {
-                    return Ok(line[colon_pos + 1..].trim().to_string());
-                }
-            }
-        }
-        
-        Err(io::Error::new(io::ErrorKind::NotFound, "Model not found"))
+    /// Only the sysfs `online` list is read again, so long-running daemons
+    /// can track CPU hotplug without a full rescan. `total_logical_cores` and
+    /// the per-core data are left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cpu_info::CpuInfo;
+    ///
+    /// let mut cpu_info = CpuInfo::new();
+    /// cpu_info.refresh_online();
+    /// println!("{:?} of {:?} cores online", cpu_info.online_logical_cores, cpu_info.total_logical_cores);
+    /// ```
+    pub fn refresh_online(&mut self) {
+        self.online_logical_cores = Self::get_online_logical_cores(Path::new(SYSFS_CPU_ROOT));
     }
 
-    /// Counts all logical CPU cores by scanning `/sys/devices/system/cpu`.
+    /// Reads the current CPU package temperature in degrees Celsius.
     ///
-    /// This method counts all `cpuN` directories regardless of cpufreq availability.
-    fn get_total_logical_cores() -> Option<usize> {
-        let count = fs::read_dir("/sys/devices/system/cpu")
+    /// Scans `/sys/class/hwmon` for a `coretemp` (Intel) or `k10temp` (AMD)
+    /// device and reads its `temp1_input`, which holds millidegrees. With
+    /// several packages the hottest one is returned. Returns `None` when no
+    /// such device exists (e.g. the driver isn't loaded, or inside a VM).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cpu_info::CpuInfo;
+    ///
+    /// if let Some(temp) = CpuInfo::package_temperature_celsius() {
+    ///     println!("Package: {:.1} °C", temp);
+    /// }
+    /// ```
+    pub fn package_temperature_celsius() -> Option<f32> {
+        fs::read_dir("/sys/class/hwmon")
             .ok()?
             .flatten()
             .filter(|entry| {
-                entry.file_name()
-                    .to_str()
-                    .and_then(|s| s.strip_prefix("cpu"))
-                    .map_or(false, |rest| rest.parse::<u32>().is_ok())
+                fs::read_to_string(entry.path().join("name"))
+                    .is_ok_and(|name| matches!(name.trim(), "coretemp" | "k10temp"))
+            })
+            .filter_map(|entry| {
+                fs::read_to_string(entry.path().join("temp1_input"))
+                    .ok()?
+                    .trim()
+                    .parse::<i32>()
+                    .ok()
             })
-            .count();
+            .max()
+            .map(|millidegrees| millidegrees as f32 / 1000.0)
+    }
 
-        if count > 0 {
-            Some(count)
-        } else {
-            None
-        }
+    /// Reads the configured package power limit in watts.
+    ///
+    /// Reads the long-term RAPL limit of the first package from
+    /// `/sys/class/powercap/intel-rapl:0/constraint_0_power_limit_uw`, which
+    /// holds microwatts. Returns `None` when RAPL isn't exposed (e.g. many
+    /// AMD and ARM systems, or inside a VM).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cpu_info::CpuInfo;
+    ///
+    /// if let Some(watts) = CpuInfo::power_limit_watts() {
+    ///     println!("Package power limit: {:.0} W", watts);
+    /// }
+    /// ```
+    pub fn power_limit_watts() -> Option<f64> {
+        fs::read_to_string("/sys/class/powercap/intel-rapl:0/constraint_0_power_limit_uw")
+            .ok()?
+            .trim()
+            .parse::<u64>()
+            .ok()
+            .map(|microwatts| microwatts as f64 / 1_000_000.0)
     }
 
-    /// Counts physical cores by reading topology information from sysfs.
+    /// Returns `true` if every core uses the `performance` cpufreq governor.
     ///
-    /// This is used as a fallback when detailed core information is unavailable.
-    fn get_total_physical_cores_fallback() -> Option<usize> {
-        let mut core_ids = HashSet::new();
-        
-        let entries = fs::read_dir("/sys/devices/system/cpu").ok()?;
-        
-        for entry in entries.flatten() {
-            let name = entry.file_name();
-            let name_str = name.to_str()?;
-            
-            if let Some(rest) = name_str.strip_prefix("cpu") {
-                if rest.parse::<u32>().is_ok() {
-                    let core_id_path = entry.path().join("topology/core_id");
-                    if let Ok(core_id_str) = fs::read_to_string(core_id_path) {
-                        if let Ok(core_id) = core_id_str.trim().parse::<u32>() {
-                            core_ids.insert(core_id);
-                        }
-                    }
-                }
-            }
-        }
-        
-        if core_ids.is_empty() {
-            None
-        } else {
-            Some(core_ids.len())
-        }
+    /// The governors are read live from sysfs, since `Lineal` CPUs don't keep
+    /// their per-core list. Returns `false` when any core uses another
+    /// governor or when no core exposes one at all (no cpufreq support).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cpu_info::CpuInfo;
+    ///
+    /// if !CpuInfo::all_cores_performance() {
+    ///     eprintln!("warning: benchmarks may be throttled by the cpufreq governor");
+    /// }
+    /// ```
+    pub fn all_cores_performance() -> bool {
+        let Ok(entries) = fs::read_dir(SYSFS_CPU_ROOT) else {
+            return false;
+        };
+
+        let governors: Vec<Option<String>> = entries
+            .flatten()
+            .filter(|entry| Self::is_cpu_dir(&entry.file_name()).is_some())
+            .map(|entry| Self::get_governor(&entry.path()))
+            .collect();
+
+        !governors.is_empty()
+            && governors.iter().all(|governor| governor.as_deref() == Some("performance"))
     }
 
-    /// Detects CPU core distribution by analyzing core frequencies.
+    /// Returns `true` if any core exposes a `cpufreq` directory.
     ///
-    /// Returns `Lineal` if all cores have the same frequency (traditional CPUs),
-    /// or `Hybrid` if cores have different frequencies (e.g., Intel 12th gen+, some ARM).
-    fn detect_distribution(cores: &[Core]) -> DistributionCore {
-        // If we have no core information, return Lineal with 0 MHz
-        if cores.is_empty() || cores.iter().all(|c| c.speed_mhz == 0) {
-            return DistributionCore::Lineal { mhz: 0 };
-        }
+    /// Boards with fixed clocks (or kernels built without cpufreq) have none,
+    /// in which case every frequency field is best-effort: `speed_mhz` and
+    /// `current_mhz` may come from `/proc/cpuinfo` or simply be 0. Use this
+    /// to tell a fixed-frequency CPU apart from a value that couldn't be read.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cpu_info::CpuInfo;
+    ///
+    /// if !CpuInfo::has_cpufreq() {
+    ///     println!("Fixed-frequency CPU, clock speeds may be unavailable");
+    /// }
+    /// ```
+    pub fn has_cpufreq() -> bool {
+        let Ok(entries) = fs::read_dir(SYSFS_CPU_ROOT) else {
+            return false;
+        };
 
-        // Frequency-based detection
-        let mut cores = cores.to_vec();
-        cores.sort_by_key(|c| c.speed_mhz);
+        entries
+            .flatten()
+            .filter(|entry| Self::is_cpu_dir(&entry.file_name()).is_some())
+            .any(|entry| entry.path().join("cpufreq").is_dir())
+    }
 
-        let all_same = cores.windows(2).all(|w| w[0].speed_mhz == w[1].speed_mhz);
-        
-        if all_same {
-            return DistributionCore::Lineal { 
-                mhz: cores[0].speed_mhz 
-            };
-        }
+    /// Returns the active cpufreq scaling driver, e.g. `"intel_pstate"`,
+    /// `"amd-pstate"` or `"acpi-cpufreq"`.
+    ///
+    /// Reads `cpu0/cpufreq/scaling_driver`. Returns `None` when cpu0 has no
+    /// cpufreq directory (see [`CpuInfo::has_cpufreq`]).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cpu_info::CpuInfo;
+    ///
+    /// match CpuInfo::scaling_driver() {
+    ///     Some(driver) => println!("cpufreq driver: {}", driver),
+    ///     None => println!("no cpufreq driver loaded"),
+    /// }
+    /// ```
+    pub fn scaling_driver() -> Option<String> {
+        fs::read_to_string(Path::new(SYSFS_CPU_ROOT).join("cpu0/cpufreq/scaling_driver"))
+            .ok()
+            .map(|driver| driver.trim().to_string())
+            .filter(|driver| !driver.is_empty())
+    }
 
-        // If not uniform, store all cores with their individual frequencies
-        DistributionCore::Hybrid { 
-            groups: cores 
+    /// Reads whether SMT (Hyper-Threading) can be switched at runtime.
+    ///
+    /// Reads `smt/control`, which kernels since 4.19 expose. Returns `None`
+    /// when the file is missing or holds a value this crate doesn't know.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cpu_info::{CpuInfo, SmtControl};
+    ///
+    /// match CpuInfo::smt_control() {
+    ///     Some(SmtControl::On | SmtControl::Off) => println!("SMT can be toggled"),
+    ///     Some(state) => println!("SMT is fixed: {:?}", state),
+    ///     None => println!("SMT control unavailable"),
+    /// }
+    /// ```
+    pub fn smt_control() -> Option<SmtControl> {
+        let state = fs::read_to_string(Path::new(SYSFS_CPU_ROOT).join("smt/control")).ok()?;
+        match state.trim() {
+            "on" => Some(SmtControl::On),
+            "off" => Some(SmtControl::Off),
+            "forceoff" => Some(SmtControl::ForceOff),
+            "notsupported" => Some(SmtControl::NotSupported),
+            "notimplemented" => Some(SmtControl::NotImplemented),
+            _ => None,
         }
     }
 
-    /// Reads detailed information for all CPU cores.
+    /// Returns whether turbo boost (Intel Turbo Boost, AMD Core Performance
+    /// Boost) is currently enabled.
     ///
-    /// This method attempts to read frequency and topology information for each core.
-    /// Cores are included even if frequency information is unavailable (speed_mhz = 0),
-    /// which is useful for accurate physical core counting.
-    fn get_cores() -> Vec<Core> {
-        let mut cores = Vec::new();
+    /// Reads `intel_pstate/no_turbo` (inverted) for the `intel_pstate` driver,
+    /// or the generic `cpufreq/boost` used by `amd_pstate` and `acpi-cpufreq`.
+    /// Returns `None` when neither control file exists.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cpu_info::CpuInfo;
+    ///
+    /// match CpuInfo::turbo_enabled() {
+    ///     Some(true) => println!("Turbo: on"),
+    ///     Some(false) => println!("Turbo: off"),
+    ///     None => println!("Turbo: unknown"),
+    /// }
+    /// ```
+    pub fn turbo_enabled() -> Option<bool> {
+        Self::get_turbo_enabled(Path::new(SYSFS_CPU_ROOT))
+    }
 
-        let Ok(entries) = fs::read_dir("/sys/devices/system/cpu") else {
-            return cores;
+    /// Reads the turbo state relative to `root`; see [`CpuInfo::turbo_enabled`].
+    fn get_turbo_enabled(root: &Path) -> Option<bool> {
+        let read_flag = |path: PathBuf| {
+            fs::read_to_string(path)
+                .ok()
+                .and_then(|s| s.trim().parse::<u8>().ok())
+                .map(|value| value != 0)
         };
 
-        for entry in entries.flatten() {
-            let name = entry.file_name();
-            let name = match name.to_str() {
-                Some(s) if s.starts_with("cpu") => s,
-                _ => continue,
-            };
+        read_flag(root.join("intel_pstate/no_turbo"))
+            .map(|no_turbo| !no_turbo)
+            .or_else(|| read_flag(root.join("cpufreq/boost")))
+    }
 
-            let id: u32 = match name[3..].parse() {
-                Ok(id) => id,
-                Err(_) => continue,
-            };
+    /// Returns the logical cores isolated from the scheduler with `isolcpus`.
+    ///
+    /// Reads the `isolated` CPU list (e.g. `2-3,6`) and expands it into core
+    /// ids. Returns an empty vec when no core is isolated or the file is absent.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cpu_info::CpuInfo;
+    ///
+    /// for id in CpuInfo::isolated_cores() {
+    ///     println!("cpu{} is reserved for pinned real-time threads", id);
+    /// }
+    /// ```
+    pub fn isolated_cores() -> Vec<u32> {
+        Self::read_cpu_list_file(&Path::new(SYSFS_CPU_ROOT).join("isolated"))
+    }
 
-            let cpu_path = entry.path();
+    /// Returns the groups of logical cores sharing a cache at `level`.
+    ///
+    /// Reads every `cache/indexM/shared_cpu_list` in sysfs, so each L2 or L3
+    /// instance yields one group of core ids, e.g. `[[0, 1, 2, 3]]` when
+    /// cpu0-3 share a single L3. Split L1 data and instruction caches with
+    /// the same sharing count once. Groups are sorted by their lowest core
+    /// id; the result is empty if sysfs doesn't describe caches.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cpu_info::CpuInfo;
+    ///
+    /// for group in CpuInfo::cores_sharing_cache(3) {
+    ///     println!("L3 shared by cores {:?}", group);
+    /// }
+    /// ```
+    pub fn cores_sharing_cache(level: u8) -> Vec<Vec<u32>> {
+        Self::get_cores_sharing_cache(Path::new(SYSFS_CPU_ROOT), level)
+    }
 
-            // Try to read frequency (may not exist on some systems)
-            let speed_khz = fs::read_to_string(cpu_path.join("cpufreq/cpuinfo_max_freq"))
-                .or_else(|_| fs::read_to_string(cpu_path.join("cpufreq/scaling_max_freq")))
-                .ok()
-                .and_then(|s| s.trim().parse::<u32>().ok())
-                .unwrap_or(0);
+    /// Reads the cache sharing groups relative to `root`; see
+    /// [`CpuInfo::cores_sharing_cache`].
+    fn get_cores_sharing_cache(root: &Path, level: u8) -> Vec<Vec<u32>> {
+        let groups: BTreeSet<Vec<u32>> = Self::get_caches_sysfs(root)
+            .into_iter()
+            .filter(|cache| cache.level == level)
+            .map(|cache| cache.shared_cpu_ids)
+            .collect();
 
-            // Read physical core ID (should always exist)
-            let physical_core_id = fs::read_to_string(cpu_path.join("topology/core_id"))
-                .ok()
-                .and_then(|s| s.trim().parse::<u32>().ok());
+        groups.into_iter().collect()
+    }
 
-            // Include the core even if speed_khz is 0
-            // (useful for accurate physical core counting)
-            cores.push(Core {
-                id,
-                speed_mhz: speed_khz / 1000,
-                physical_core_id,
-            });
-        }
+    /// Reads the kernel's status for every known CPU vulnerability.
+    ///
+    /// Each file under `vulnerabilities/` (`meltdown`, `spectre_v1`, ...)
+    /// becomes one entry with its content kept verbatim, sorted by name.
+    /// Returns an empty vec on kernels older than 4.15, which lack the
+    /// directory.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cpu_info::CpuInfo;
+    ///
+    /// for vulnerability in CpuInfo::vulnerabilities() {
+    ///     println!("{}: {}", vulnerability.name, vulnerability.status);
+    /// }
+    /// ```
+    pub fn vulnerabilities() -> Vec<Vulnerability> {
+        let Ok(entries) = fs::read_dir(Path::new(SYSFS_CPU_ROOT).join("vulnerabilities")) else {
+            return Vec::new();
+        };
 
-        cores
+        let mut vulnerabilities: Vec<Vulnerability> = entries
+            .flatten()
+            .filter_map(|entry| {
+                let status = fs::read_to_string(entry.path()).ok()?;
+                Some(Vulnerability {
+                    name: entry.file_name().to_string_lossy().into_owned(),
+                    status: status.trim().to_string(),
+                })
+            })
+            .collect();
+
+        vulnerabilities.sort_by(|a, b| a.name.cmp(&b.name));
+        vulnerabilities
+    }
+
+    /// Reads the idle states (C-states) the kernel can put cpu0 into.
+    ///
+    /// Each `cpu0/cpuidle/stateN` directory becomes one entry, shallowest
+    /// first (`POLL`, `C1`, `C1E`, `C6`, ...). Returns an empty vec when
+    /// cpuidle isn't available, e.g. in most VMs or with `cpuidle.off=1`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cpu_info::CpuInfo;
+    ///
+    /// for state in CpuInfo::idle_states() {
+    ///     println!("{}: {} us exit latency", state.name, state.latency_us);
+    /// }
+    /// ```
+    pub fn idle_states() -> Vec<IdleState> {
+        let Ok(entries) = fs::read_dir(Path::new(SYSFS_CPU_ROOT).join("cpu0/cpuidle")) else {
+            return Vec::new();
+        };
+
+        let mut states: Vec<(u32, IdleState)> = entries
+            .flatten()
+            .filter_map(|entry| {
+                let index = entry.file_name().to_str()?.strip_prefix("state")?.parse::<u32>().ok()?;
+                let path = entry.path();
+                let read_us = |file: &str| {
+                    fs::read_to_string(path.join(file))
+                        .ok()?
+                        .trim()
+                        .parse::<u32>()
+                        .ok()
+                };
+
+                let state = IdleState {
+                    name: fs::read_to_string(path.join("name")).ok()?.trim().to_string(),
+                    latency_us: read_us("latency")?,
+                    residency_us: read_us("residency")?,
+                };
+                Some((index, state))
+            })
+            .collect();
+
+        states.sort_by_key(|(index, _)| *index);
+        states.into_iter().map(|(_, state)| state).collect()
+    }
+
+    /// Returns the number of CPUs this process can effectively use.
+    ///
+    /// Containers are often limited by a cgroup CPU quota while still seeing
+    /// every host core. This reads the cgroup v2 `cpu.max` or the v1
+    /// `cpu.cfs_quota_us`/`cpu.cfs_period_us` pair of the current process
+    /// (and its parent groups), rounds the budget up to whole CPUs and clamps
+    /// it to `total_logical_cores`. Without a quota the logical core count is
+    /// returned as-is.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cpu_info::CpuInfo;
+    ///
+    /// let cpu_info = CpuInfo::new();
+    /// let workers = cpu_info.available_parallelism().unwrap_or(1);
+    /// println!("Spawning {} worker threads", workers);
+    /// ```
+    pub fn available_parallelism(&self) -> Option<usize> {
+        match Self::get_cgroup_cpu_limit() {
+            Some(limit) => Some(self.total_logical_cores.map_or(limit, |total| limit.min(total))),
+            None => self.total_logical_cores,
+        }
+    }
+
+    /// Returns the logical cores the current process is allowed to run on.
+    ///
+    /// Calls `sched_getaffinity` through `libc` (a dependency of the `linux`
+    /// feature), so cores excluded with `taskset` or a cgroup cpuset are left
+    /// out. Unlike `online_logical_cores` the mask is per-process. Returns an
+    /// empty vec if the call fails, e.g. on machines with more than 1024 CPUs.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cpu_info::CpuInfo;
+    ///
+    /// println!("Allowed to run on cores {:?}", CpuInfo::allowed_cores());
+    /// ```
+    pub fn allowed_cores() -> Vec<u32> {
+        let mut set: libc::cpu_set_t = unsafe { std::mem::zeroed() };
+        let result = unsafe { libc::sched_getaffinity(0, std::mem::size_of::<libc::cpu_set_t>(), &mut set) };
+        if result != 0 {
+            return Vec::new();
+        }
+
+        (0..libc::CPU_SETSIZE as usize)
+            .filter(|&cpu| unsafe { libc::CPU_ISSET(cpu, &set) })
+            .map(|cpu| cpu as u32)
+            .collect()
+    }
+
+    /// Reads the tightest cgroup CPU quota of the current process, in CPUs.
+    ///
+    /// `/proc/self/cgroup` names the group of every hierarchy: `0::/path` for
+    /// cgroup v2 and `N:cpu,cpuacct:/path` for the v1 `cpu` controller. The
+    /// group and all of its ancestors are checked, since a limit set on a
+    /// parent applies to its children too. Returns `None` when no quota is set.
+    fn get_cgroup_cpu_limit() -> Option<usize> {
+        let cgroups = fs::read_to_string("/proc/self/cgroup").unwrap_or_default();
+        let mut limits = Vec::new();
+
+        for line in cgroups.lines() {
+            let mut parts = line.splitn(3, ':');
+            let (Some(_), Some(controllers), Some(group)) = (parts.next(), parts.next(), parts.next()) else {
+                continue;
+            };
+            let group = Path::new(group.trim_start_matches('/'));
+
+            if controllers.is_empty() {
+                for dir in group.ancestors() {
+                    let base = Path::new("/sys/fs/cgroup").join(dir);
+                    let Ok(cpu_max) = fs::read_to_string(base.join("cpu.max")) else {
+                        continue;
+                    };
+                    let mut fields = cpu_max.split_whitespace();
+                    if let (Some(quota), Some(period)) = (fields.next(), fields.next()) {
+                        limits.extend(Self::parse_cpu_quota(quota, period));
+                    }
+                }
+            } else if controllers.split(',').any(|c| c == "cpu") {
+                for mount in ["/sys/fs/cgroup/cpu,cpuacct", "/sys/fs/cgroup/cpu"] {
+                    for dir in group.ancestors() {
+                        let base = Path::new(mount).join(dir);
+                        let read = |file: &str| fs::read_to_string(base.join(file)).ok();
+                        if let (Some(quota), Some(period)) = (read("cpu.cfs_quota_us"), read("cpu.cfs_period_us")) {
+                            limits.extend(Self::parse_cpu_quota(quota.trim(), period.trim()));
+                        }
+                    }
+                }
+            }
+        }
+
+        limits.into_iter().min()
+    }
+
+    /// Converts a cgroup quota and period (both in microseconds) into whole CPUs.
+    ///
+    /// `max` (v2) or a negative quota (v1) means unlimited. Partial CPUs are
+    /// rounded up, so a quota of 1.5 CPUs allows 2 threads.
+    fn parse_cpu_quota(quota: &str, period: &str) -> Option<usize> {
+        let quota = quota.parse::<i64>().ok().filter(|&q| q > 0)? as u64;
+        let period = period.parse::<u64>().ok().filter(|&p| p > 0)?;
+        Some(quota.div_ceil(period).max(1) as usize)
+    }
+
+    /// Derives the physical core count from detailed core info.
+    ///
+    /// Uses the topology fallback when no core reports a physical ID.
+    fn get_total_physical_cores(root: &Path, cores: &[Core]) -> Option<usize> {
+        let physical_ids: HashSet<u32> = cores
+            .iter()
+            .filter_map(|core| core.physical_core_id)
+            .collect();
+
+        if physical_ids.is_empty() {
+            Self::get_total_physical_cores_fallback(root)
+        } else {
+            Some(physical_ids.len())
+        }
+    }
+
+    /// Detects the CPU manufacturer/vendor.
+    ///
+    /// On x86/x86_64, this uses the CPUID instruction for language-independent
+    /// detection, or the `vendor_id` line of `/proc/cpuinfo` when CPUID is
+    /// unusable (some sandboxes).
+    /// On ARM, RISC-V, s390x, PowerPC, LoongArch and other architectures, it
+    /// reads from `/proc/cpuinfo`.
+    fn get_fabricant() -> Result<Fabricant, CpuInfoError> {
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            Self::get_fabricant_cpuid().or_else(|_| Self::get_fabricant_procfs())
+        }
+
+        #[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+        {
+            Self::get_fabricant_riscv()
+        }
+
+        #[cfg(any(target_arch = "s390x", target_arch = "powerpc", target_arch = "powerpc64"))]
+        {
+            Self::get_fabricant_s390x_powerpc()
+        }
+
+        #[cfg(target_arch = "loongarch64")]
+        {
+            Self::get_fabricant_loongarch()
+        }
+
+        #[cfg(not(any(
+            target_arch = "x86",
+            target_arch = "x86_64",
+            target_arch = "riscv32",
+            target_arch = "riscv64",
+            target_arch = "s390x",
+            target_arch = "powerpc",
+            target_arch = "powerpc64",
+            target_arch = "loongarch64"
+        )))]
+        {
+            Self::get_fabricant_arm()
+        }
+    }
+
+    /// Detects CPU manufacturer on LoongArch from `/proc/cpuinfo`.
+    ///
+    /// The kernel builds `Model Name` (e.g. `Loongson-3A5000`) and `CPU Family`
+    /// from the CPUCFG vendor and CPU name, so a `Loongson` prefix there
+    /// identifies the vendor.
+    #[cfg(target_arch = "loongarch64")]
+    fn get_fabricant_loongarch() -> Result<Fabricant, CpuInfoError> {
+        let content = fs::read_to_string("/proc/cpuinfo").map_err(CpuInfoError::ProcfsUnavailable)?;
+
+        let is_loongson = content.lines().any(|line| {
+            line.split_once(':').is_some_and(|(key, value)| {
+                matches!(key.trim(), "Model Name" | "CPU Family") && value.trim().starts_with("Loongson")
+            })
+        });
+
+        Ok(if is_loongson {
+            Fabricant::Other("Loongson".to_string())
+        } else {
+            Fabricant::Unknown
+        })
+    }
+
+    /// Detects CPU manufacturer on s390x and PowerPC from `/proc/cpuinfo`.
+    ///
+    /// s390x reports `vendor_id : IBM/S390`. PowerPC has no vendor line, so
+    /// the `cpu` line (`POWER9 (raw), altivec supported`) and the `machine`
+    /// line (`PowerMac7,3`) identify IBM and Apple systems.
+    #[cfg(any(target_arch = "s390x", target_arch = "powerpc", target_arch = "powerpc64"))]
+    fn get_fabricant_s390x_powerpc() -> Result<Fabricant, CpuInfoError> {
+        let content = fs::read_to_string("/proc/cpuinfo").map_err(CpuInfoError::ProcfsUnavailable)?;
+
+        let field = |name: &str| Self::cpuinfo_field(&content, name);
+
+        if let Some(vendor) = field("vendor_id") {
+            let vendor = vendor.split('/').next().unwrap_or_default();
+            return Ok(Fabricant::Other(vendor.to_string()));
+        }
+
+        let is_power = field("cpu").is_some_and(|cpu| cpu.starts_with("POWER"));
+        Ok(match field("machine") {
+            _ if is_power => Fabricant::Other("IBM".to_string()),
+            Some(machine) if machine.starts_with("PowerMac") => Fabricant::Apple,
+            _ => Fabricant::Unknown,
+        })
+    }
+
+    /// Reads the x86 vendor string from the `vendor_id` line of `/proc/cpuinfo`.
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    fn get_fabricant_procfs() -> Result<Fabricant, CpuInfoError> {
+        let content = fs::read_to_string("/proc/cpuinfo").map_err(CpuInfoError::ProcfsUnavailable)?;
+        Ok(Self::parse_cpuinfo_fabricant(&content))
+    }
+
+    /// Detects CPU manufacturer on RISC-V by reading the `mvendorid` CSR value.
+    ///
+    /// The value is a JEDEC manufacturer ID; 0 means the core is a
+    /// non-commercial implementation and only `marchid` identifies it.
+    #[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+    fn get_fabricant_riscv() -> Result<Fabricant, CpuInfoError> {
+        let content = fs::read_to_string("/proc/cpuinfo").map_err(CpuInfoError::ProcfsUnavailable)?;
+
+        let field = |name: &str| Self::cpuinfo_field(&content, name);
+
+        let Some(vendor) = field("mvendorid") else {
+            return Ok(Fabricant::Unknown);
+        };
+        let vendor_id = u64::from_str_radix(vendor.trim_start_matches("0x"), 16).ok();
+
+        Ok(match vendor_id {
+            Some(0x489) => Fabricant::Other("SiFive".to_string()),
+            Some(0x5b7) => Fabricant::Other("T-Head".to_string()),
+            Some(0x31e) => Fabricant::Other("Andes".to_string()),
+            Some(0) => match field("marchid") {
+                Some(arch) => Fabricant::Other(format!("marchid {}", arch)),
+                None => Fabricant::Unknown,
+            },
+            _ => Fabricant::Other(vendor.to_string()),
+        })
+    }
+
+    /// Detects CPU manufacturer on ARM by reading the implementer ID.
+    ///
+    /// This method parses hexadecimal implementer IDs and maps them to known vendors.
+    #[cfg(not(any(
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "riscv32",
+        target_arch = "riscv64"
+    )))]
+    fn get_fabricant_arm() -> Result<Fabricant, CpuInfoError> {
+        let content = fs::read_to_string("/proc/cpuinfo").map_err(CpuInfoError::ProcfsUnavailable)?;
+        
+        for line in content.lines() {
+            if let Some(colon_pos) = line.find(':') {
+                let key = line[..colon_pos].trim().to_lowercase();
+                if key.contains("implementer") {
+                    let value = line[colon_pos + 1..].trim();
+                    
+                    // Parse the value (can be "0x41" or "41")
+                    let implementer = if let Some(hex_str) = value.strip_prefix("0x") {
+                        u32::from_str_radix(hex_str, 16).ok()
+                    } else {
+                        u32::from_str_radix(value, 16).ok()
+                    };
+                    
+                    return Ok(match implementer {
+                        Some(0x41) => Fabricant::Arm,
+                        Some(0x42) => Fabricant::Broadcom,
+                        Some(0x43) => Fabricant::Other("Cavium".to_string()),
+                        Some(0x44) => Fabricant::Other("DEC".to_string()),
+                        Some(0x4e) => Fabricant::Nvidia,
+                        Some(0x50) => Fabricant::Other("APM".to_string()),
+                        Some(0x51) => Fabricant::Qualcomm,
+                        Some(0x56) => Fabricant::Other("Marvell".to_string()),
+                        Some(0x61) => Fabricant::Apple,
+                        _ => Fabricant::Other(value.to_string()),
+                    });
+                }
+            }
+        }
+        
+        Ok(Fabricant::Unknown)
+    }
+
+    /// Detects instruction-set extensions.
+    ///
+    /// On x86/x86_64, this uses CPUID, or the `flags` line of `/proc/cpuinfo`
+    /// when CPUID is unusable.
+    /// On ARM and other architectures, it reads the `Features` line of `/proc/cpuinfo`.
+    fn get_features() -> Vec<CpuFeature> {
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            if Self::cpuid_usable() {
+                return Self::get_features_cpuid();
+            }
+        }
+
+        Self::get_features_procfs()
+    }
+
+    /// Reads instruction-set extensions from the `flags` (x86) or `Features`
+    /// (ARM) line of `/proc/cpuinfo`.
+    ///
+    /// AArch64 kernels report NEON as `asimd`, 32-bit ARM kernels as `neon`.
+    fn get_features_procfs() -> Vec<CpuFeature> {
+        fs::read_to_string("/proc/cpuinfo")
+            .map(|content| Self::parse_cpuinfo_features(&content))
+            .unwrap_or_default()
+    }
+
+    /// Reads the running microcode revision.
+    ///
+    /// Uses `cpu0/microcode/version`, falling back to the `microcode` line of
+    /// `/proc/cpuinfo`. Both hold a hex value such as `0xf0`. Only x86 has
+    /// microcode revisions; other architectures return `None`.
+    fn get_microcode(root: &Path) -> Option<u64> {
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            if let Ok(version) = fs::read_to_string(root.join("cpu0/microcode/version"))
+                && let Some(revision) = Self::parse_microcode_revision(&version)
+            {
+                return Some(revision);
+            }
+
+            let content = fs::read_to_string("/proc/cpuinfo").ok()?;
+            Self::parse_cpuinfo_microcode(&content)
+        }
+
+        #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+        {
+            let _ = root;
+            None
+        }
+    }
+
+    /// Reads the `address sizes` line of `/proc/cpuinfo`.
+    ///
+    /// The line looks like `address sizes : 39 bits physical, 48 bits virtual`
+    /// and is only present on x86.
+    fn get_address_bits_procfs() -> Option<AddressBits> {
+        let content = fs::read_to_string("/proc/cpuinfo").ok()?;
+        Self::parse_cpuinfo_address_bits(&content)
+    }
+
+    /// Detects the CPU model name.
+    ///
+    /// On x86/x86_64, this uses CPUID for reliable detection. On ARM, which
+    /// has no brand string, it is built from the implementer and core part
+    /// names (e.g. `"ARM Cortex-A55 + Cortex-A78"`).
+    /// Falls back to reading `/proc/cpuinfo` otherwise.
+    /// Returns `Ok(None)` when no source reports a model name.
+    fn get_model() -> Result<Option<String>, CpuInfoError> {
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            if let Ok(model) = Self::get_model_cpuid() {
+                return Ok(Some(model));
+            }
+        }
+
+        #[cfg(not(any(
+            target_arch = "x86",
+            target_arch = "x86_64",
+            target_arch = "riscv32",
+            target_arch = "riscv64"
+        )))]
+        {
+            if let Some(model) = Self::get_model_arm() {
+                return Ok(Some(model));
+            }
+        }
+
+        Self::get_model_procfs()
+    }
+
+    /// Builds an ARM model name from the implementer and the distinct core parts.
+    ///
+    /// Parts are listed in order of their first logical core, so a big.LITTLE
+    /// system reads `"ARM Cortex-A55 + Cortex-A78"`. Returns `None` when no
+    /// part number is known.
+    #[cfg(not(any(
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "riscv32",
+        target_arch = "riscv64"
+    )))]
+    fn get_model_arm() -> Option<String> {
+        let mut parts: Vec<String> = Vec::new();
+        for part in Self::get_part_names().into_values() {
+            if !parts.contains(&part) {
+                parts.push(part);
+            }
+        }
+
+        if parts.is_empty() {
+            return None;
+        }
+
+        let parts = parts.join(" + ");
+        Some(match Self::get_fabricant_arm() {
+            Ok(Fabricant::Unknown) | Err(_) => parts,
+            Ok(fabricant) => format!("{} {}", fabricant, parts),
+        })
+    }
+
+    /// Reads the core part name of every logical core.
+    ///
+    /// Each processor block of `/proc/cpuinfo` on ARM has a `CPU implementer`
+    /// and `CPU part` pair (the MIDR fields), mapped here to names such as
+    /// `Cortex-A78`. Other architectures return an empty map.
+    fn get_part_names() -> BTreeMap<u32, String> {
+        #[cfg(not(any(
+            target_arch = "x86",
+            target_arch = "x86_64",
+            target_arch = "riscv32",
+            target_arch = "riscv64"
+        )))]
+        {
+            let Ok(content) = fs::read_to_string("/proc/cpuinfo") else {
+                return BTreeMap::new();
+            };
+
+            let parse_hex = |value: &str| {
+                u32::from_str_radix(value.strip_prefix("0x").unwrap_or(value), 16).ok()
+            };
+
+            content
+                .split("\n\n")
+                .filter_map(|block| {
+                    let mut id = None;
+                    let mut implementer = None;
+                    let mut part = None;
+
+                    for line in block.lines() {
+                        let Some((key, value)) = line.split_once(':') else {
+                            continue;
+                        };
+                        let value = value.trim();
+
+                        match key.trim() {
+                            "processor" => id = value.parse::<u32>().ok(),
+                            "CPU implementer" => implementer = parse_hex(value),
+                            "CPU part" => part = parse_hex(value),
+                            _ => {}
+                        }
+                    }
+
+                    let name = Self::arm_part_name(implementer?, part?)?;
+                    Some((id?, name.to_string()))
+                })
+                .collect()
+        }
+
+        #[cfg(any(
+            target_arch = "x86",
+            target_arch = "x86_64",
+            target_arch = "riscv32",
+            target_arch = "riscv64"
+        ))]
+        {
+            BTreeMap::new()
+        }
+    }
+
+    /// Reads a core's clock from the device tree, for ARM boards without cpufreq.
+    ///
+    /// `cpus/cpu@N/clock-frequency` holds the frequency in Hz as a big-endian
+    /// u32 (u64 on a few boards). Other architectures return `None`.
+    fn get_devicetree_khz(root: &Path, id: u32) -> Option<u32> {
+        #[cfg(not(any(
+            target_arch = "x86",
+            target_arch = "x86_64",
+            target_arch = "riscv32",
+            target_arch = "riscv64"
+        )))]
+        {
+            let path = root.join(format!(
+                "../../../firmware/devicetree/base/cpus/cpu@{}/clock-frequency",
+                id
+            ));
+            let bytes = fs::read(path).ok()?;
+            let hz = match bytes.as_slice() {
+                [a, b, c, d] => u64::from(u32::from_be_bytes([*a, *b, *c, *d])),
+                bytes => u64::from_be_bytes(bytes.try_into().ok()?),
+            };
+            u32::try_from(hz / 1000).ok().filter(|&khz| khz > 0)
+        }
+
+        #[cfg(any(
+            target_arch = "x86",
+            target_arch = "x86_64",
+            target_arch = "riscv32",
+            target_arch = "riscv64"
+        ))]
+        {
+            let _ = (root, id);
+            None
+        }
+    }
+
+    /// Maps an ARM implementer and part number to a core name.
+    ///
+    /// Values follow the kernel's `arch/arm64/include/asm/cputype.h`.
+    #[cfg(not(any(
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "riscv32",
+        target_arch = "riscv64"
+    )))]
+    fn arm_part_name(implementer: u32, part: u32) -> Option<&'static str> {
+        let name = match (implementer, part) {
+            (0x41, 0xc07) => "Cortex-A7",
+            (0x41, 0xc09) => "Cortex-A9",
+            (0x41, 0xc0f) => "Cortex-A15",
+            (0x41, 0xd03) => "Cortex-A53",
+            (0x41, 0xd04) => "Cortex-A35",
+            (0x41, 0xd05) => "Cortex-A55",
+            (0x41, 0xd07) => "Cortex-A57",
+            (0x41, 0xd08) => "Cortex-A72",
+            (0x41, 0xd09) => "Cortex-A73",
+            (0x41, 0xd0a) => "Cortex-A75",
+            (0x41, 0xd0b) => "Cortex-A76",
+            (0x41, 0xd0c) => "Neoverse-N1",
+            (0x41, 0xd0d) => "Cortex-A77",
+            (0x41, 0xd40) => "Neoverse-V1",
+            (0x41, 0xd41) => "Cortex-A78",
+            (0x41, 0xd44) => "Cortex-X1",
+            (0x41, 0xd46) => "Cortex-A510",
+            (0x41, 0xd47) => "Cortex-A710",
+            (0x41, 0xd48) => "Cortex-X2",
+            (0x41, 0xd49) => "Neoverse-N2",
+            (0x41, 0xd4b) => "Cortex-A78C",
+            (0x41, 0xd4d) => "Cortex-A715",
+            (0x41, 0xd4e) => "Cortex-X3",
+            (0x41, 0xd4f) => "Neoverse-V2",
+            (0x41, 0xd80) => "Cortex-A520",
+            (0x41, 0xd81) => "Cortex-A720",
+            (0x41, 0xd82) => "Cortex-X4",
+            (0x51, 0x800) => "Kryo 2xx Gold",
+            (0x51, 0x801) => "Kryo 2xx Silver",
+            (0x51, 0x802) => "Kryo 3xx Gold",
+            (0x51, 0x803) => "Kryo 3xx Silver",
+            (0x51, 0x804) => "Kryo 4xx Gold",
+            (0x51, 0x805) => "Kryo 4xx Silver",
+            (0x51, 0xc00) => "Falkor",
+            (0x61, 0x022) => "Icestorm",
+            (0x61, 0x023) => "Firestorm",
+            _ => return None,
+        };
+        Some(name)
+    }
+
+    /// Reads the CPU model name from `/proc/cpuinfo`.
+    ///
+    /// See [`CpuInfo::parse_cpuinfo_model`] for the lines it looks at.
+    fn get_model_procfs() -> Result<Option<String>, CpuInfoError> {
+        let content = fs::read_to_string("/proc/cpuinfo").map_err(CpuInfoError::ProcfsUnavailable)?;
+        Ok(Self::parse_cpuinfo_model(&content).map(str::to_string))
+    }
+
+    /// Counts all logical CPU cores by scanning the sysfs CPU directory.
+    ///
+    /// This method counts all `cpuN` directories regardless of cpufreq availability.
+    /// When sysfs is unreadable or lists no CPUs (locked-down containers), it
+    /// falls back to `sysconf(_SC_NPROCESSORS_ONLN)`, which only sees online CPUs.
+    fn get_total_logical_cores(root: &Path) -> Result<Option<usize>, CpuInfoError> {
+        let count = match fs::read_dir(root) {
+            Ok(entries) => entries
+                .flatten()
+                .filter(|entry| Self::is_cpu_dir(&entry.file_name()).is_some())
+                .count(),
+            Err(e) => {
+                return match Self::get_total_logical_cores_sysconf() {
+                    Some(count) => Ok(Some(count)),
+                    None => Err(CpuInfoError::SysfsUnavailable(e)),
+                };
+            }
+        };
+
+        if count > 0 {
+            Ok(Some(count))
+        } else {
+            Ok(Self::get_total_logical_cores_sysconf())
+        }
+    }
+
+    /// Counts online logical cores with `sysconf(_SC_NPROCESSORS_ONLN)`.
+    fn get_total_logical_cores_sysconf() -> Option<usize> {
+        let count = unsafe { libc::sysconf(libc::_SC_NPROCESSORS_ONLN) };
+        (count > 0).then_some(count as usize)
+    }
+
+    /// Counts the logical cores listed in the sysfs `online` file (e.g. `0-3,6-7`).
+    ///
+    /// Returns `None` if the file is missing, as on kernels without hotplug support.
+    fn get_online_logical_cores(root: &Path) -> Option<usize> {
+        let online = Self::read_cpu_list_file(&root.join("online"));
+        (!online.is_empty()).then_some(online.len())
+    }
+
+    /// Counts physical cores by reading topology information from sysfs.
+    ///
+    /// This is used as a fallback when detailed core information is unavailable.
+    fn get_total_physical_cores_fallback(root: &Path) -> Option<usize> {
+        let mut core_ids = HashSet::new();
+        
+        let entries = fs::read_dir(root).ok()?;
+        
+        for entry in entries.flatten() {
+            if Self::is_cpu_dir(&entry.file_name()).is_some() {
+                let core_id_path = entry.path().join("topology/core_id");
+                if let Ok(core_id_str) = fs::read_to_string(core_id_path)
+                    && let Ok(core_id) = core_id_str.trim().parse::<u32>()
+                {
+                    core_ids.insert(core_id);
+                }
+            }
+        }
+        
+        if core_ids.is_empty() {
+            None
+        } else {
+            Some(core_ids.len())
+        }
+    }
+
+    /// Detects whether SMT is active.
+    ///
+    /// Reads `smt/active` (`1`/`0`) when the kernel provides it, otherwise
+    /// compares the logical and physical core counts.
+    fn get_smt_enabled(root: &Path, logical: Option<usize>, physical: Option<usize>) -> Option<bool> {
+        match fs::read_to_string(root.join("smt/active")).map(|s| s.trim().to_string()) {
+            Ok(active) if active == "1" => Some(true),
+            Ok(active) if active == "0" => Some(false),
+            _ => Self::smt_from_counts(logical, physical),
+        }
+    }
+
+    /// Counts CPU sockets (packages) by reading topology information from sysfs.
+    ///
+    /// Returns `None` if no core exposes `topology/physical_package_id`.
+    fn get_total_sockets(root: &Path) -> Option<usize> {
+        let mut package_ids = HashSet::new();
+
+        let entries = fs::read_dir(root).ok()?;
+
+        for entry in entries.flatten() {
+            if Self::is_cpu_dir(&entry.file_name()).is_none() {
+                continue;
+            }
+
+            if let Ok(package_id) = fs::read_to_string(entry.path().join("topology/physical_package_id"))
+                && let Ok(package_id) = package_id.trim().parse::<i32>()
+            {
+                package_ids.insert(package_id);
+            }
+        }
+
+        if package_ids.is_empty() {
+            None
+        } else {
+            Some(package_ids.len())
+        }
+    }
+
+    /// Reads detailed information for all CPU cores.
+    ///
+    /// This method attempts to read frequency and topology information for each core.
+    /// Cores are included even if frequency information is unavailable (speed_mhz = 0),
+    /// which is useful for accurate physical core counting. Frequencies come
+    /// from the shared cpufreq policies when the kernel has them, and ARM
+    /// boards without cpufreq fall back to the device-tree clock.
+    fn get_cores(root: &Path) -> Vec<Core> {
+        let mut cores = Vec::new();
+
+        let Ok(entries) = fs::read_dir(root) else {
+            return Self::get_cores_procfs();
+        };
+
+        let part_names = Self::get_part_names();
+        let policies = Self::get_cpufreq_policies(root);
+
+        // Intel hybrid CPUs register one PMU per core type, each listing its CPUs
+        let pmu_cores = Self::read_cpu_list_file(&root.join("../../cpu_core/cpus"));
+        let pmu_atoms = Self::read_cpu_list_file(&root.join("../../cpu_atom/cpus"));
+
+        for entry in entries.flatten() {
+            let Some(id) = Self::is_cpu_dir(&entry.file_name()) else {
+                continue;
+            };
+
+            let cpu_path = entry.path();
+
+            // Try to read frequency (may not exist on some systems). The
+            // hardware limit and the policy limit differ when the BIOS caps the clock
+            let policy = policies
+                .get(&id)
+                .cloned()
+                .unwrap_or_else(|| CpufreqPolicy::read(&cpu_path.join("cpufreq")));
+            let speed_khz = policy
+                .hardware_max_khz
+                .or(policy.scaling_max_khz)
+                .or_else(|| Self::get_devicetree_khz(root, id))
+                .unwrap_or(0);
+
+            // Read physical core ID (should always exist)
+            let physical_core_id = fs::read_to_string(cpu_path.join("topology/core_id"))
+                .ok()
+                .and_then(|s| s.trim().parse::<u32>().ok());
+
+            let core_type = fs::read_to_string(cpu_path.join("topology/core_type"))
+                .ok()
+                .map(|s| Self::parse_core_type(&s))
+                .or_else(|| {
+                    if pmu_cores.contains(&id) {
+                        Some(CoreType::Performance)
+                    } else if pmu_atoms.contains(&id) {
+                        Some(CoreType::Efficient)
+                    } else {
+                        None
+                    }
+                });
+
+            // Arm/RISC-V topology code publishes cpu_capacity. ACPI CPPC
+            // highest_perf is not used: AMD preferred-core parts rank otherwise
+            // identical cores with it, which would look hybrid
+            let capacity = fs::read_to_string(cpu_path.join("cpu_capacity"))
+                .ok()
+                .and_then(|s| s.trim().parse::<u32>().ok());
+
+            // Include the core even if speed_khz is 0
+            // (useful for accurate physical core counting)
+            cores.push(Core {
+                current_mhz: policy.current_khz / 1000,
+                base_mhz: policy.base_khz / 1000,
+                min_mhz: policy.min_khz / 1000,
+                scaling_max_mhz: policy.scaling_max_khz.unwrap_or(0) / 1000,
+                hardware_max_mhz: policy.hardware_max_khz.unwrap_or(0) / 1000,
+                core_type,
+                capacity,
+                governor: policy.governor,
+                part_name: part_names.get(&id).cloned(),
+                siblings: Self::read_cpu_list_file(&cpu_path.join("topology/thread_siblings_list")),
+                ..Core::from_khz(id, speed_khz, physical_core_id)
+            });
+        }
+
+        // Some containers only bind-mount procfs
+        if cores.is_empty() {
+            return Self::get_cores_procfs();
+        }
+
+        cores
+    }
+
+    /// Reads every `cpufreq/policyN` directory once and maps each CPU in
+    /// its `affected_cpus` to the policy.
+    ///
+    /// CPUs sharing a clock domain share one policy, so on large systems this
+    /// is far fewer reads than going through every `cpuN/cpufreq`. CPUs
+    /// missing from the map (older kernels without policy directories) are
+    /// read per core by the caller.
+    fn get_cpufreq_policies(root: &Path) -> BTreeMap<u32, CpufreqPolicy> {
+        let mut policies = BTreeMap::new();
+
+        let Ok(entries) = fs::read_dir(root.join("cpufreq")) else {
+            return policies;
+        };
+
+        for entry in entries.flatten() {
+            let is_policy = entry
+                .file_name()
+                .to_str()
+                .and_then(|name| name.strip_prefix("policy"))
+                .is_some_and(|n| n.parse::<u32>().is_ok());
+            if !is_policy {
+                continue;
+            }
+
+            // Space-separated, unlike the range lists elsewhere in sysfs
+            let Ok(affected) = fs::read_to_string(entry.path().join("affected_cpus")) else {
+                continue;
+            };
+
+            let policy = CpufreqPolicy::read(&entry.path());
+            for cpu in affected.split_whitespace().filter_map(|cpu| cpu.parse::<u32>().ok()) {
+                policies.insert(cpu, policy.clone());
+            }
+        }
+
+        policies
+    }
+
+    /// Reads the cpufreq scaling governor of one `cpuN` directory.
+    ///
+    /// Returns `None` when the kernel has no cpufreq support for the core.
+    fn get_governor(cpu_path: &Path) -> Option<String> {
+        fs::read_to_string(cpu_path.join("cpufreq/scaling_governor"))
+            .ok()
+            .map(|s| s.trim().to_string())
+    }
+
+    /// Reads per-core information from the processor blocks of `/proc/cpuinfo`.
+    ///
+    /// Fallback for systems without sysfs. `cpu MHz` is the current frequency
+    /// (e.g. `"3592.968"`), so it is rounded and used as both the maximum and
+    /// current speed. Returns an empty vec if procfs is unavailable too.
+    fn get_cores_procfs() -> Vec<Core> {
+        let Ok(content) = fs::read_to_string("/proc/cpuinfo") else {
+            return Vec::new();
+        };
+
+        let part_names = Self::get_part_names();
+        let mut cores = Self::parse_cpuinfo_cores(&content);
+        for core in &mut cores {
+            core.part_name = part_names.get(&core.id).cloned();
+        }
+
+        cores
+    }
+
+    /// Reads the NUMA topology from `/sys/devices/system/node`.
+    ///
+    /// The node directory sits next to the CPU directory. Returns an empty
+    /// vector when it doesn't exist (UMA systems, kernels without NUMA).
+    fn get_numa_nodes(root: &Path) -> Vec<NumaNode> {
+        let Ok(entries) = fs::read_dir(root.join("../node")) else {
+            return Vec::new();
+        };
+
+        let mut nodes: Vec<NumaNode> = entries
+            .flatten()
+            .filter_map(|entry| {
+                let id = entry.file_name().to_str()?.strip_prefix("node")?.parse::<u32>().ok()?;
+                let cpus = Self::read_cpu_list_file(&entry.path().join("cpulist"));
+                Some(NumaNode { id, cpus })
+            })
+            .collect();
+
+        nodes.sort_by_key(|node| node.id);
+        nodes
+    }
+
+    /// Groups cores into clusters using `cpuN/topology/cluster_id`.
+    ///
+    /// Some kernels report `-1` or omit the file; in that case cores are
+    /// clustered by maximum frequency instead, numbered from the slowest
+    /// group. Returns an empty vector when neither source is available.
+    fn get_clusters(root: &Path, cores: &[Core]) -> Vec<CpuCluster> {
+        let cluster_ids: Option<Vec<u32>> = cores
+            .iter()
+            .map(|core| {
+                fs::read_to_string(root.join(format!("cpu{}/topology/cluster_id", core.id)))
+                    .ok()?
+                    .trim()
+                    .parse::<u32>()
+                    .ok()
+            })
+            .collect();
+
+        // Cluster id (or frequency) -> member cores
+        let mut groups: BTreeMap<u32, Vec<&Core>> = BTreeMap::new();
+        let cluster_ids = cluster_ids.filter(|ids| !ids.is_empty());
+        let by_cluster_id = cluster_ids.is_some();
+        match cluster_ids {
+            Some(ids) => {
+                for (core, id) in cores.iter().zip(ids) {
+                    groups.entry(id).or_default().push(core);
+                }
+            }
+            None => {
+                if cores.iter().all(|core| core.speed_mhz == 0) {
+                    return Vec::new();
+                }
+                for core in cores {
+                    groups.entry(core.speed_mhz).or_default().push(core);
+                }
+            }
+        }
+
+        groups
+            .into_iter()
+            .enumerate()
+            .map(|(index, (key, members))| {
+                let mut cpus: Vec<u32> = members.iter().map(|core| core.id).collect();
+                cpus.sort_unstable();
+                CpuCluster {
+                    id: if by_cluster_id { key } else { index as u32 },
+                    cpus,
+                    max_mhz: members.iter().map(|core| core.speed_mhz).max().unwrap_or(0),
+                }
+            })
+            .collect()
+    }
+
+    /// Detects the cache hierarchy.
+    ///
+    /// On x86/x86_64 CPUID is tried first so sandboxes without sysfs still
+    /// report caches, and the sharing lists are then taken from sysfs when it
+    /// is readable. ARM and other architectures, or CPUs without the cache
+    /// parameters leaf, read sysfs.
+    fn get_caches_linux(root: &Path) -> Vec<CacheInfo> {
+        let caches = Self::get_caches();
+        let sysfs = Self::get_caches_sysfs(root);
+        if caches.is_empty() {
+            return sysfs;
+        }
+
+        Self::merge_cache_sharing(caches, &sysfs)
+    }
+
+    /// Fills `shared_cpu_ids` of CPUID caches from the sysfs caches of the
+    /// same level and kind.
+    ///
+    /// CPUID describes one instance per cache, so an entry is repeated for
+    /// every sysfs instance (e.g. one L2 per core), each with that instance's
+    /// core ids. Entries sysfs doesn't describe are kept as they are.
+    fn merge_cache_sharing(caches: Vec<CacheInfo>, sysfs: &[CacheInfo]) -> Vec<CacheInfo> {
+        caches
+            .into_iter()
+            .flat_map(|cache| {
+                let instances: Vec<&CacheInfo> = sysfs
+                    .iter()
+                    .filter(|instance| instance.level == cache.level && instance.kind == cache.kind)
+                    .collect();
+                if instances.is_empty() {
+                    return vec![cache];
+                }
+
+                instances
+                    .into_iter()
+                    .map(|instance| CacheInfo {
+                        shared_by: instance.shared_cpu_ids.len(),
+                        shared_cpu_ids: instance.shared_cpu_ids.clone(),
+                        ..cache.clone()
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Reads cache information for all CPU cores from sysfs.
+    ///
+    /// Each `cpuN/cache/indexM` directory describes one cache seen by that core.
+    /// Caches shared between cores (same level, type and `shared_cpu_list`) are
+    /// only reported once, so an L3 appears once per socket.
+    fn get_caches_sysfs(root: &Path) -> Vec<CacheInfo> {
+        let mut caches = Vec::new();
+        let mut seen = HashSet::new();
+
+        let Ok(entries) = fs::read_dir(root) else {
+            return caches;
+        };
+
+        // Visit cores in id order so the output is deterministic
+        let mut cpus: Vec<(u32, PathBuf)> = entries
+            .flatten()
+            .filter_map(|entry| {
+                let id = Self::is_cpu_dir(&entry.file_name())?;
+                Some((id, entry.path()))
+            })
+            .collect();
+        cpus.sort_by_key(|(id, _)| *id);
+
+        for (id, cpu_path) in cpus {
+            let Ok(indexes) = fs::read_dir(cpu_path.join("cache")) else {
+                continue;
+            };
+
+            let mut index_paths: Vec<PathBuf> = indexes
+                .flatten()
+                .filter(|entry| entry.file_name().to_str().is_some_and(|s| s.starts_with("index")))
+                .map(|entry| entry.path())
+                .collect();
+            index_paths.sort();
+
+            for index_path in index_paths {
+                let read = |file: &str| {
+                    fs::read_to_string(index_path.join(file))
+                        .ok()
+                        .map(|s| s.trim().to_string())
+                };
+
+                let Some(level) = read("level").and_then(|s| s.parse::<u8>().ok()) else {
+                    continue;
+                };
+
+                let kind = match read("type").as_deref() {
+                    Some("Data") => CacheKind::Data,
+                    Some("Instruction") => CacheKind::Instruction,
+                    Some("Unified") => CacheKind::Unified,
+                    _ => continue,
+                };
+
+                // Without a sharing list the cache is assumed private to this core
+                let shared_cpus = read("shared_cpu_list")
+                    .map(|s| Self::parse_cpu_list(&s))
+                    .filter(|cpus| !cpus.is_empty())
+                    .unwrap_or_else(|| vec![id]);
+
+                if !seen.insert((level, kind, shared_cpus.clone())) {
+                    continue;
+                }
+
+                caches.push(CacheInfo {
+                    level,
+                    kind,
+                    size_bytes: read("size").and_then(|s| Self::parse_cache_size(&s)).unwrap_or(0),
+                    line_size: read("coherency_line_size")
+                        .and_then(|s| s.parse::<u32>().ok())
+                        .unwrap_or(0),
+                    shared_by: shared_cpus.len(),
+                    shared_cpu_ids: shared_cpus,
+                });
+            }
+        }
+
+        caches.sort_by_key(|c| c.level);
+        caches
+    }
+
+    /// Parses a sysfs cache size such as `32K`, `1024K` or `16M` into bytes.
+    ///
+    /// Plain numbers are taken as bytes.
+    fn parse_cache_size(value: &str) -> Option<u64> {
+        let value = value.trim();
+        let (digits, multiplier) = match value.char_indices().last()? {
+            (i, 'K') | (i, 'k') => (&value[..i], 1024),
+            (i, 'M') | (i, 'm') => (&value[..i], 1024 * 1024),
+            (i, 'G') | (i, 'g') => (&value[..i], 1024 * 1024 * 1024),
+            _ => (value, 1),
+        };
+
+        digits.trim().parse::<u64>().ok().map(|n| n * multiplier)
+    }
+
+    /// Maps a sysfs core type name to a `CoreType`.
+    fn parse_core_type(value: &str) -> CoreType {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "performance" | "core" | "intel_core" => CoreType::Performance,
+            "efficient" | "efficiency" | "atom" | "intel_atom" => CoreType::Efficient,
+            _ => CoreType::Unknown,
+        }
+    }
+
+    /// Returns the CPU id if `name` is a per-CPU directory such as `cpu12`.
+    ///
+    /// Siblings like `cpufreq`, `cpuidle` or `cpu` itself are rejected, as is
+    /// anything but plain ASCII digits after the prefix (e.g. `cpu+1`).
+    fn is_cpu_dir(name: &OsStr) -> Option<u32> {
+        let digits = name.to_str()?.strip_prefix("cpu")?;
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        digits.parse().ok()
+    }
+
+    /// Reads a file holding a kernel CPU list, returning an empty list if absent.
+    fn read_cpu_list_file(path: &Path) -> Vec<u32> {
+        fs::read_to_string(path)
+            .map(|s| Self::parse_cpu_list(&s))
+            .unwrap_or_default()
+    }
+
+    /// Expands a kernel CPU list such as `0-3,8-11` into individual CPU ids.
+    ///
+    /// Malformed fragments are skipped.
+    fn parse_cpu_list(list: &str) -> Vec<u32> {
+        let mut cpus = Vec::new();
+
+        for part in list.trim().split(',') {
+            let part = part.trim();
+            if let Some((start, end)) = part.split_once('-') {
+                if let (Ok(start), Ok(end)) = (start.parse::<u32>(), end.parse::<u32>()) {
+                    cpus.extend(start..=end);
+                }
+            } else if let Ok(cpu) = part.parse::<u32>() {
+                cpus.push(cpu);
+            }
+        }
+
+        cpus
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// A fake `/sys/devices/system/cpu` tree in a temporary directory.
+    ///
+    /// The tree is nested like the real one, so paths such as `../node` and
+    /// `../../cpu_core` resolve inside it. It is removed on drop.
+    struct Fixture {
+        dir: PathBuf,
+        root: PathBuf,
+    }
+
+    impl Fixture {
+        fn new() -> Self {
+            static NEXT: AtomicUsize = AtomicUsize::new(0);
+
+            let dir = std::env::temp_dir().join(format!(
+                "cpu_info-{}-{}",
+                std::process::id(),
+                NEXT.fetch_add(1, Ordering::Relaxed)
+            ));
+            let root = dir.join("sys/devices/system/cpu");
+            fs::create_dir_all(&root).unwrap();
+
+            Fixture { dir, root }
+        }
+
+        /// Writes `content` to `path`, relative to the cpu root.
+        fn write(&self, path: &str, content: &str) -> &Self {
+            let path = self.root.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+            self
+        }
+
+        /// Adds `cpuN` with a physical core id and a maximum frequency in kHz.
+        fn cpu(&self, id: u32, core_id: u32, max_khz: u32) -> &Self {
+            self.write(&format!("cpu{id}/topology/core_id"), &format!("{core_id}\n"))
+                .write(&format!("cpu{id}/cpufreq/cpuinfo_max_freq"), &format!("{max_khz}\n"))
+        }
+    }
+
+    impl Drop for Fixture {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.dir);
+        }
+    }
+
+    #[test]
+    fn from_sysfs_root_counts_smt_siblings_once() {
+        let sysfs = Fixture::new();
+        sysfs
+            .cpu(0, 0, 3_600_000)
+            .cpu(1, 1, 3_600_000)
+            .cpu(2, 0, 3_600_000)
+            .cpu(3, 1, 3_600_000);
+
+        let info = CpuInfo::from_sysfs_root(&sysfs.root);
+
+        assert_eq!(info.total_logical_cores, Some(4));
+        assert_eq!(info.total_physical_cores, Some(2));
+        assert_eq!(info.distribution, DistributionCore::Lineal { mhz: 3600, base_mhz: 0 });
+    }
+
+    #[test]
+    fn from_sysfs_root_detects_hybrid_frequencies() {
+        let sysfs = Fixture::new();
+        sysfs
+            .cpu(0, 0, 4_800_000)
+            .cpu(1, 1, 4_800_000)
+            .cpu(2, 2, 3_600_000)
+            .cpu(3, 3, 3_600_000)
+            .cpu(4, 4, 3_600_000);
+
+        let info = CpuInfo::from_sysfs_root(&sysfs.root);
+
+        assert_eq!(info.total_logical_cores, Some(5));
+        assert_eq!(info.total_physical_cores, Some(5));
+        let DistributionCore::Hybrid { groups } = info.distribution else {
+            panic!("expected a hybrid distribution");
+        };
+        let speeds: Vec<(u32, u32)> = groups.iter().map(|core| (core.id, core.speed_mhz)).collect();
+        assert_eq!(speeds, [(0, 4800), (1, 4800), (2, 3600), (3, 3600), (4, 3600)]);
+    }
+
+    #[test]
+    fn get_turbo_enabled_inverts_intel_no_turbo() {
+        let sysfs = Fixture::new();
+
+        sysfs.write("intel_pstate/no_turbo", "0\n");
+        assert_eq!(CpuInfo::get_turbo_enabled(&sysfs.root), Some(true));
+
+        sysfs.write("intel_pstate/no_turbo", "1\n");
+        assert_eq!(CpuInfo::get_turbo_enabled(&sysfs.root), Some(false));
+    }
+
+    #[test]
+    fn get_turbo_enabled_reads_cpufreq_boost() {
+        let sysfs = Fixture::new();
+
+        sysfs.write("cpufreq/boost", "1\n");
+        assert_eq!(CpuInfo::get_turbo_enabled(&sysfs.root), Some(true));
+
+        sysfs.write("cpufreq/boost", "0\n");
+        assert_eq!(CpuInfo::get_turbo_enabled(&sysfs.root), Some(false));
+    }
+
+    #[test]
+    fn get_turbo_enabled_without_either_file() {
+        let sysfs = Fixture::new();
+        sysfs.cpu(0, 0, 3_600_000);
+
+        assert_eq!(CpuInfo::get_turbo_enabled(&sysfs.root), None);
+    }
+
+    #[test]
+    fn is_cpu_dir_only_matches_numbered_cpus() {
+        for name in ["cpufreq", "cpuidle", "cpu", "cpu1a"] {
+            assert_eq!(CpuInfo::is_cpu_dir(OsStr::new(name)), None, "{name}");
+        }
+        assert_eq!(CpuInfo::is_cpu_dir(OsStr::new("cpu0")), Some(0));
+        assert_eq!(CpuInfo::is_cpu_dir(OsStr::new("cpu12")), Some(12));
+    }
+
+    #[test]
+    fn get_total_sockets_counts_package_ids() {
+        let sysfs = Fixture::new();
+        for id in 0..4 {
+            sysfs.cpu(id, id % 2, 3_600_000).write(
+                &format!("cpu{id}/topology/physical_package_id"),
+                &format!("{}\n", id / 2),
+            );
+        }
+
+        assert_eq!(CpuInfo::get_total_sockets(&sysfs.root), Some(2));
+    }
+
+    #[test]
+    fn get_total_sockets_without_package_ids() {
+        let sysfs = Fixture::new();
+        sysfs.cpu(0, 0, 3_600_000);
+
+        assert_eq!(CpuInfo::get_total_sockets(&sysfs.root), None);
+    }
+
+    #[test]
+    fn get_smt_enabled_prefers_smt_active() {
+        let sysfs = Fixture::new();
+
+        sysfs.write("smt/active", "0\n");
+        assert_eq!(CpuInfo::get_smt_enabled(&sysfs.root, Some(8), Some(8)), Some(false));
+
+        sysfs.write("smt/active", "1\n");
+        assert_eq!(CpuInfo::get_smt_enabled(&sysfs.root, Some(8), Some(8)), Some(true));
+    }
+
+    #[test]
+    fn get_smt_enabled_falls_back_to_counts() {
+        let sysfs = Fixture::new();
+
+        assert_eq!(CpuInfo::get_smt_enabled(&sysfs.root, Some(16), Some(8)), Some(true));
+        assert_eq!(CpuInfo::get_smt_enabled(&sysfs.root, Some(8), Some(8)), Some(false));
+    }
+
+    #[test]
+    fn parse_cpu_list_expands_ranges() {
+        assert_eq!(CpuInfo::parse_cpu_list("0-3,8-11\n"), [0, 1, 2, 3, 8, 9, 10, 11]);
+        assert_eq!(CpuInfo::parse_cpu_list("0,2,4-5"), [0, 2, 4, 5]);
+        assert_eq!(CpuInfo::parse_cpu_list("7"), [7]);
+        assert!(CpuInfo::parse_cpu_list("\n").is_empty());
+        // Malformed fragments are skipped
+        assert_eq!(CpuInfo::parse_cpu_list("0-x,3"), [3]);
+    }
+
+    #[test]
+    fn get_numa_nodes_reads_node_cpulists() {
+        let sysfs = Fixture::new();
+        sysfs
+            .write("../node/node1/cpulist", "4-7,12-15\n")
+            .write("../node/node0/cpulist", "0-3,8-11\n")
+            .write("../node/possible", "0-1\n");
+
+        let nodes = CpuInfo::get_numa_nodes(&sysfs.root);
+
+        assert_eq!(
+            nodes,
+            [
+                NumaNode { id: 0, cpus: vec![0, 1, 2, 3, 8, 9, 10, 11] },
+                NumaNode { id: 1, cpus: vec![4, 5, 6, 7, 12, 13, 14, 15] },
+            ]
+        );
+    }
+
+    #[test]
+    fn get_numa_nodes_without_node_directory() {
+        let sysfs = Fixture::new();
+
+        assert!(CpuInfo::get_numa_nodes(&sysfs.root).is_empty());
+    }
+
+    #[test]
+    fn online_cores_exclude_offline_ones() {
+        let sysfs = Fixture::new();
+        for id in 0..6 {
+            sysfs.cpu(id, id, 3_600_000);
+        }
+        sysfs.write("online", "0-1,4\n");
+
+        assert_eq!(CpuInfo::get_online_logical_cores(&sysfs.root), Some(3));
+        assert_eq!(CpuInfo::get_total_logical_cores(&sysfs.root).unwrap(), Some(6));
+    }
+
+    #[test]
+    fn online_cores_without_online_file() {
+        let sysfs = Fixture::new();
+        sysfs.cpu(0, 0, 3_600_000);
+
+        assert_eq!(CpuInfo::get_online_logical_cores(&sysfs.root), None);
+    }
+
+    #[test]
+    fn get_cores_reports_governor_per_core() {
+        let sysfs = Fixture::new();
+        for id in 0..4 {
+            sysfs.cpu(id, id, 3_600_000);
+        }
+        sysfs
+            .write("cpufreq/policy0/affected_cpus", "0 1\n")
+            .write("cpufreq/policy0/scaling_governor", "performance\n")
+            .write("cpufreq/policy2/affected_cpus", "2\n")
+            .write("cpufreq/policy2/scaling_governor", "powersave\n");
+        // cpu3 has no policy directory and is read through cpu3/cpufreq
+
+        let mut cores = CpuInfo::get_cores(&sysfs.root);
+        cores.sort_by_key(|core| core.id);
+        let governors: Vec<Option<&str>> = cores.iter().map(|core| core.governor.as_deref()).collect();
+
+        assert_eq!(governors, [Some("performance"), Some("performance"), Some("powersave"), None]);
+    }
+
+    #[test]
+    fn get_cpufreq_policies_maps_affected_cpus() {
+        let sysfs = Fixture::new();
+        sysfs
+            .write("cpufreq/policy0/affected_cpus", "0 1 2 3\n")
+            .write("cpufreq/policy0/cpuinfo_max_freq", "4800000\n")
+            .write("cpufreq/policy4/affected_cpus", "4 5\n")
+            .write("cpufreq/policy4/cpuinfo_max_freq", "3600000\n")
+            .write("cpufreq/boost", "1\n");
+
+        let policies = CpuInfo::get_cpufreq_policies(&sysfs.root);
+
+        assert_eq!(policies.keys().copied().collect::<Vec<_>>(), [0, 1, 2, 3, 4, 5]);
+        assert_eq!(policies[&3].hardware_max_khz, Some(4_800_000));
+        assert_eq!(policies[&5].hardware_max_khz, Some(3_600_000));
+    }
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    #[test]
+    fn get_microcode_reads_sysfs_version() {
+        let sysfs = Fixture::new();
+        sysfs.cpu(0, 0, 3_600_000).write("cpu0/microcode/version", "0xf0\n");
+
+        assert_eq!(CpuInfo::get_microcode(&sysfs.root), Some(0xf0));
+    }
+
+    #[test]
+    fn get_cores_reads_thread_siblings() {
+        let sysfs = Fixture::new();
+        for id in 0..8 {
+            sysfs.cpu(id, id % 4, 3_600_000).write(
+                &format!("cpu{id}/topology/thread_siblings_list"),
+                &format!("{},{}\n", id % 4, id % 4 + 4),
+            );
+        }
+
+        let cores = CpuInfo::get_cores(&sysfs.root);
+        let siblings = |id: u32| &cores.iter().find(|core| core.id == id).unwrap().siblings;
+
+        assert_eq!(siblings(0), &[0, 4]);
+        assert_eq!(siblings(4), &[0, 4]);
+        assert_eq!(siblings(1), &[1, 5]);
+    }
+
+    #[test]
+    fn get_cores_reports_scaling_and_hardware_max_separately() {
+        let sysfs = Fixture::new();
+        sysfs
+            .cpu(0, 0, 4_800_000)
+            .write("cpu0/cpufreq/scaling_max_freq", "2600000\n");
+
+        let cores = CpuInfo::get_cores(&sysfs.root);
+
+        assert_eq!(cores[0].scaling_max_mhz, 2600);
+        assert_eq!(cores[0].hardware_max_mhz, 4800);
+        assert_eq!(cores[0].speed_mhz, 4800);
+    }
+
+    #[test]
+    fn cppc_highest_perf_does_not_make_cores_hybrid() {
+        let sysfs = Fixture::new();
+        // AMD preferred cores: same clock, different CPPC ranking
+        for (id, highest_perf) in [(0, 231), (1, 226), (2, 221), (3, 216)] {
+            sysfs
+                .cpu(id, id, 4_700_000)
+                .write(&format!("cpu{id}/acpi_cppc/highest_perf"), &format!("{highest_perf}\n"));
+        }
+
+        let cores = CpuInfo::get_cores(&sysfs.root);
+
+        assert!(cores.iter().all(|core| core.capacity.is_none()));
+        assert_eq!(
+            CpuInfo::detect_distribution(&cores),
+            DistributionCore::Lineal { mhz: 4700, base_mhz: 0 }
+        );
+    }
+
+    #[test]
+    fn cpu_capacity_detects_big_little_at_the_same_clock() {
+        let sysfs = Fixture::new();
+        for (id, capacity) in [(0, 446), (1, 446), (2, 1024), (3, 1024)] {
+            sysfs
+                .cpu(id, id, 2_000_000)
+                .write(&format!("cpu{id}/cpu_capacity"), &format!("{capacity}\n"));
+        }
+
+        let cores = CpuInfo::get_cores(&sysfs.root);
+
+        assert!(matches!(CpuInfo::detect_distribution(&cores), DistributionCore::Hybrid { .. }));
+    }
+
+    #[test]
+    fn merge_cache_sharing_copies_sysfs_core_ids() {
+        let sysfs = Fixture::new();
+        for id in 0..4 {
+            sysfs
+                .cpu(id, id, 3_600_000)
+                .write(&format!("cpu{id}/cache/index0/level"), "1\n")
+                .write(&format!("cpu{id}/cache/index0/type"), "Data\n")
+                .write(&format!("cpu{id}/cache/index0/size"), "48K\n")
+                .write(&format!("cpu{id}/cache/index0/shared_cpu_list"), &format!("{id}\n"))
+                .write(&format!("cpu{id}/cache/index3/level"), "3\n")
+                .write(&format!("cpu{id}/cache/index3/type"), "Unified\n")
+                .write(&format!("cpu{id}/cache/index3/size"), "32768K\n")
+                .write(&format!("cpu{id}/cache/index3/shared_cpu_list"), "0-3\n");
+        }
+        let cpuid_cache = |level: u8, kind: CacheKind, size_bytes: u64, shared_by: usize| CacheInfo {
+            level,
+            kind,
+            size_bytes,
+            line_size: 64,
+            shared_by,
+            shared_cpu_ids: Vec::new(),
+        };
+        let cpuid = vec![
+            cpuid_cache(1, CacheKind::Data, 48 * 1024, 1),
+            cpuid_cache(2, CacheKind::Unified, 1024 * 1024, 1),
+            cpuid_cache(3, CacheKind::Unified, 32 * 1024 * 1024, 4),
+        ];
+
+        let caches = CpuInfo::merge_cache_sharing(cpuid, &CpuInfo::get_caches_sysfs(&sysfs.root));
+
+        let shared = |level: u8| -> Vec<Vec<u32>> {
+            caches
+                .iter()
+                .filter(|cache| cache.level == level)
+                .map(|cache| cache.shared_cpu_ids.clone())
+                .collect()
+        };
+        assert_eq!(shared(1), [vec![0], vec![1], vec![2], vec![3]]);
+        // sysfs has no L2 here, so the CPUID entry is kept without ids
+        assert_eq!(shared(2), [Vec::<u32>::new()]);
+        assert_eq!(shared(3), [vec![0, 1, 2, 3]]);
+
+        let l3 = caches.iter().find(|cache| cache.level == 3).unwrap();
+        assert_eq!(l3.shared_by, 4);
+        assert_eq!(l3.size_bytes, 32 * 1024 * 1024);
+        assert_eq!(l3.line_size, 64);
+        assert_eq!(CpuInfo::get_cores_sharing_cache(&sysfs.root, 3), [vec![0, 1, 2, 3]]);
+    }
+
+    #[test]
+    fn parse_cache_size_handles_suffixes() {
+        assert_eq!(CpuInfo::parse_cache_size("48K"), Some(48 * 1024));
+        assert_eq!(CpuInfo::parse_cache_size("32M\n"), Some(32 * 1024 * 1024));
+        assert_eq!(CpuInfo::parse_cache_size("512"), Some(512));
+        assert_eq!(CpuInfo::parse_cache_size("K"), None);
+    }
+
+    #[test]
+    fn parse_cpuinfo_microcode_reads_hex() {
+        let cpuinfo = "processor\t: 0\nvendor_id\t: GenuineIntel\nmicrocode\t: 0xf0\n";
+
+        assert_eq!(CpuInfo::parse_cpuinfo_microcode(cpuinfo), Some(0xf0));
+        assert_eq!(CpuInfo::parse_cpuinfo_microcode("processor\t: 0\n"), None);
+    }
+
+    #[test]
+    fn procfs_fallback_parses_vendor_model_and_flags() {
+        let cpuinfo = "\
+processor\t: 0
+vendor_id\t: AuthenticAMD
+cpu family\t: 25
+model\t\t: 33
+model name\t: AMD Ryzen 7 5800X 8-Core Processor
+flags\t\t: fpu sse sse2 pni ssse3 sse4_1 sse4_2 popcnt aes avx avx2 rdrand rdseed
+";
+
+        assert_eq!(CpuInfo::parse_cpuinfo_fabricant(cpuinfo), Fabricant::Amd);
+        assert_eq!(CpuInfo::parse_cpuinfo_model(cpuinfo), Some("AMD Ryzen 7 5800X 8-Core Processor"));
+        assert_eq!(
+            CpuInfo::parse_cpuinfo_features(cpuinfo),
+            [
+                CpuFeature::Sse,
+                CpuFeature::Sse2,
+                CpuFeature::Sse3,
+                CpuFeature::Ssse3,
+                CpuFeature::Sse4_1,
+                CpuFeature::Sse4_2,
+                CpuFeature::Popcnt,
+                CpuFeature::Aes,
+                CpuFeature::Avx,
+                CpuFeature::Avx2,
+                CpuFeature::Rdrand,
+                CpuFeature::Rdseed,
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_cpuinfo_model_falls_back_to_cpu_family() {
+        let cpuinfo = "system type\t: generic-loongson-machine\n\nprocessor\t: 0\nCPU Family\t: Loongson-64bit\n";
+
+        assert_eq!(CpuInfo::parse_cpuinfo_model(cpuinfo), Some("Loongson-64bit"));
+        assert_eq!(CpuInfo::parse_cpuinfo_fabricant(cpuinfo), Fabricant::Unknown);
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,111 @@
+use alloc::string::ToString;
+use crate::{CpuArchitecture, CpuInfo, DistributionCore, Fabricant};
+
+/// Parsing of `lscpu` text output.
+///
+/// Pure string handling, so it also works on archived output and without `std`.
+impl CpuInfo {
+    /// Builds a `CpuInfo` from the default output of `lscpu`.
+    ///
+    /// Useful for analyzing fleet inventories collected on other machines.
+    /// Only these lines are read:
+    ///
+    /// - `Architecture` and `Model name`
+    /// - `Vendor ID`, mapped like the CPUID vendor string on x86 and like
+    ///   the implementer name (`ARM`, `Qualcomm`, ...) elsewhere
+    /// - `CPU(s)`, `Core(s) per socket` and `Socket(s)` for the counts
+    /// - `CPU max MHz` for a `Lineal` distribution, since `lscpu` has no
+    ///   per-core frequencies
+    ///
+    /// Everything else keeps the placeholder of [`CpuInfo::default`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cpu_info::{CpuArchitecture, CpuInfo, Fabricant};
+    ///
+    /// let output = "\
+    /// Architecture:            x86_64
+    ///   CPU op-mode(s):        32-bit, 64-bit
+    /// CPU(s):                  12
+    ///   On-line CPU(s) list:   0-11
+    /// Vendor ID:               GenuineIntel
+    ///   Model name:            Intel(R) Core(TM) i7-8700K CPU @ 3.70GHz
+    ///     Thread(s) per core:  2
+    ///     Core(s) per socket:  6
+    ///     Socket(s):           1
+    ///     CPU max MHz:         4700.0000
+    ///     CPU min MHz:         800.0000
+    /// ";
+    ///
+    /// let info = CpuInfo::from_lscpu_str(output);
+    /// assert_eq!(info.architecture, CpuArchitecture::X86_64);
+    /// assert_eq!(info.fabricant, Fabricant::Intel);
+    /// assert_eq!(info.model, "Intel(R) Core(TM) i7-8700K CPU @ 3.70GHz");
+    /// assert_eq!(info.total_logical_cores, Some(12));
+    /// assert_eq!(info.total_physical_cores, Some(6));
+    /// assert_eq!(info.total_sockets, Some(1));
+    /// assert_eq!(info.smt_enabled, Some(true));
+    /// assert_eq!(info.max_frequency_mhz(), 4700);
+    /// ```
+    pub fn from_lscpu_str(s: &str) -> CpuInfo {
+        let number = |key: &str| Self::cpuinfo_field(s, key).and_then(|v| v.parse::<usize>().ok());
+
+        let total_logical_cores = number("CPU(s)");
+        let total_sockets = number("Socket(s)");
+        let total_physical_cores = match (number("Core(s) per socket"), total_sockets) {
+            (Some(cores), Some(sockets)) => Some(cores * sockets),
+            _ => None,
+        };
+
+        let mhz = Self::cpuinfo_field(s, "CPU max MHz")
+            .and_then(|v| v.parse::<f64>().ok())
+            .map_or(0, |mhz| (mhz + 0.5) as u32);
+
+        CpuInfo {
+            architecture: Self::cpuinfo_field(s, "Architecture")
+                .map_or(CpuArchitecture::Unknown, Self::parse_lscpu_architecture),
+            fabricant: Self::cpuinfo_field(s, "Vendor ID")
+                .map_or(Fabricant::Unknown, Self::parse_lscpu_vendor),
+            model: Self::cpuinfo_field(s, "Model name")
+                .unwrap_or("Unknown")
+                .to_string(),
+            total_logical_cores,
+            online_logical_cores: total_logical_cores,
+            total_physical_cores,
+            total_sockets,
+            smt_enabled: Self::smt_from_counts(total_logical_cores, total_physical_cores),
+            distribution: DistributionCore::Lineal { mhz, base_mhz: 0 },
+            ..CpuInfo::default()
+        }
+    }
+
+    /// Maps the `uname -m` machine name printed as `Architecture`.
+    fn parse_lscpu_architecture(machine: &str) -> CpuArchitecture {
+        match machine {
+            "x86_64" | "amd64" => CpuArchitecture::X86_64,
+            "i386" | "i486" | "i586" | "i686" => CpuArchitecture::X86,
+            "aarch64" | "arm64" => CpuArchitecture::ARM64,
+            "riscv64" => CpuArchitecture::RISCV64,
+            "riscv32" => CpuArchitecture::RISCV32,
+            "s390x" => CpuArchitecture::S390X,
+            "ppc" => CpuArchitecture::PowerPC,
+            "ppc64" | "ppc64le" => CpuArchitecture::PowerPC64,
+            "loongarch64" => CpuArchitecture::LoongArch64,
+            machine if machine.starts_with("arm") => CpuArchitecture::ARM,
+            _ => CpuArchitecture::Unknown,
+        }
+    }
+
+    /// Maps `Vendor ID`, which is the implementer name on ARM.
+    fn parse_lscpu_vendor(vendor: &str) -> Fabricant {
+        match vendor {
+            "ARM" => Fabricant::Arm,
+            "Qualcomm" => Fabricant::Qualcomm,
+            "Apple" => Fabricant::Apple,
+            "Broadcom" => Fabricant::Broadcom,
+            "NVIDIA" => Fabricant::Nvidia,
+            vendor => Self::fabricant_from_vendor_id(vendor),
+        }
+    }
+}
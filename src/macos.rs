@@ -0,0 +1,332 @@
+use crate::{Core,CoreType,CpuArchitecture,CpuFeature,CpuInfo,CpuInfoError,DistributionCore,Fabricant,IdleState,SmtControl,Vulnerability};
+
+#[cfg(feature = "macos")]
+impl CpuInfo {
+    /// Creates a new `CpuInfo` instance by detecting all CPU information.
+    ///
+    /// On macOS every value is queried through `sysctlbyname`, so no files
+    /// or external commands are involved.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cpu_info::{CpuInfo, DistributionCore};
+    ///
+    /// let cpu_info = CpuInfo::new();
+    /// match cpu_info.distribution {
+    ///     DistributionCore::Lineal { mhz, .. } => {
+    ///         println!("Uniform CPU with all cores at {} MHz", mhz);
+    ///     }
+    ///     DistributionCore::Hybrid { ref groups } => {
+    ///         println!("Hybrid CPU with {} cores", groups.len());
+    ///     }
+    /// }
+    /// ```
+    pub fn new() -> Self {
+        if let Some(Ok(info)) = Self::from_override() {
+            return info;
+        }
+
+        let total_logical_cores = Self::sysctl_u32("hw.logicalcpu")
+            .ok()
+            .map(|n| n as usize);
+        let total_physical_cores = Self::sysctl_u32("hw.physicalcpu")
+            .ok()
+            .map(|n| n as usize);
+
+        let distribution = Self::get_distribution_macos();
+
+        Self {
+            architecture: Self::get_architecture_macos(),
+            endianness: Self::get_endianness(),
+            fabricant: Self::get_fabricant_macos().unwrap_or(Fabricant::Unknown),
+            model: Self::get_model_macos().unwrap_or("Unknown".to_string()),
+            total_logical_cores,
+            online_logical_cores: Self::sysctl_u32("hw.activecpu").ok().map(|n| n as usize),
+            total_physical_cores,
+            total_sockets: Self::sysctl_u32("hw.packages").ok().map(|n| n as usize),
+            smt_enabled: Self::smt_from_counts(total_logical_cores, total_physical_cores),
+            distribution,
+            caches: Self::get_caches(),
+            features: Self::get_features_macos(),
+            signature: Self::get_signature(),
+            microcode: Self::get_microcode_macos(),
+            address_bits: Self::get_address_bits(),
+            hypervisor: Self::get_hypervisor(),
+            numa_nodes: Vec::new(),
+            clusters: Vec::new(),
+        }
+    }
+
+    /// Creates a new `CpuInfo` instance, reporting why detection failed.
+    ///
+    /// Unlike [`CpuInfo::new`], this returns [`CpuInfoError::Os`] when one of
+    /// the required `sysctl` values cannot be read.
+    pub fn try_new() -> Result<Self, CpuInfoError> {
+        if let Some(result) = Self::from_override() {
+            return result;
+        }
+
+        let total_logical_cores = Some(Self::sysctl_u32("hw.logicalcpu")? as usize);
+        let total_physical_cores = Some(Self::sysctl_u32("hw.physicalcpu")? as usize);
+
+        Ok(Self {
+            architecture: Self::get_architecture_macos(),
+            endianness: Self::get_endianness(),
+            fabricant: Self::get_fabricant_macos()?,
+            model: Self::get_model_macos()?,
+            total_logical_cores,
+            online_logical_cores: Self::sysctl_u32("hw.activecpu").ok().map(|n| n as usize),
+            total_physical_cores,
+            total_sockets: Self::sysctl_u32("hw.packages").ok().map(|n| n as usize),
+            smt_enabled: Self::smt_from_counts(total_logical_cores, total_physical_cores),
+            distribution: Self::get_distribution_macos(),
+            caches: Self::get_caches(),
+            features: Self::get_features_macos(),
+            signature: Self::get_signature(),
+            microcode: Self::get_microcode_macos(),
+            address_bits: Self::get_address_bits(),
+            hypervisor: Self::get_hypervisor(),
+            numa_nodes: Vec::new(),
+            clusters: Vec::new(),
+        })
+    }
+
+    /// Re-reads the current frequency of every known core.
+    ///
+    /// macOS doesn't expose per-core current frequencies through `sysctl`,
+    /// so this is a no-op.
+    pub fn refresh_frequencies(&mut self) {}
+
+    /// Re-reads how many logical cores are online.
+    ///
+    /// Only `hw.activecpu` is queried again; every other field is left untouched.
+    pub fn refresh_online(&mut self) {
+        self.online_logical_cores = Self::sysctl_u32("hw.activecpu").ok().map(|n| n as usize);
+    }
+
+    /// Reads the current CPU package temperature in degrees Celsius.
+    ///
+    /// macOS only exposes temperatures through private SMC interfaces, so
+    /// this always returns `None`.
+    pub fn package_temperature_celsius() -> Option<f32> {
+        None
+    }
+
+    /// Reads the configured package power limit in watts.
+    ///
+    /// macOS only exposes power limits through private SMC interfaces, so this
+    /// always returns `None`.
+    pub fn power_limit_watts() -> Option<f64> {
+        None
+    }
+
+    /// Returns `true` if every core uses the `performance` cpufreq governor.
+    ///
+    /// cpufreq governors are Linux-only, so this always returns `false` on
+    /// macOS.
+    pub fn all_cores_performance() -> bool {
+        false
+    }
+
+    /// Returns `true` if any core exposes a Linux `cpufreq` directory.
+    ///
+    /// Always `false` on macOS.
+    pub fn has_cpufreq() -> bool {
+        false
+    }
+
+    /// Returns the active cpufreq scaling driver.
+    ///
+    /// Always `None` on macOS, which has no cpufreq drivers.
+    pub fn scaling_driver() -> Option<String> {
+        None
+    }
+
+    /// Reads whether SMT (Hyper-Threading) can be switched at runtime.
+    ///
+    /// Only read from Linux's `smt/control`; always `None` on macOS.
+    pub fn smt_control() -> Option<SmtControl> {
+        None
+    }
+
+    /// Returns whether turbo boost is currently enabled.
+    ///
+    /// Only read from Linux's `intel_pstate` and `cpufreq` controls; always
+    /// `None` on macOS.
+    pub fn turbo_enabled() -> Option<bool> {
+        None
+    }
+
+    /// Returns the logical cores isolated from the scheduler with `isolcpus`.
+    ///
+    /// `isolcpus` is a Linux boot option, so this always returns an empty vec
+    /// on macOS.
+    pub fn isolated_cores() -> Vec<u32> {
+        Vec::new()
+    }
+
+    /// Returns the groups of logical cores sharing a cache at `level`.
+    ///
+    /// Sharing lists are only read from Linux sysfs, so this always returns
+    /// an empty vec on macOS.
+    pub fn cores_sharing_cache(_level: u8) -> Vec<Vec<u32>> {
+        Vec::new()
+    }
+
+    /// Reads the kernel's status for every known CPU vulnerability.
+    ///
+    /// Only read from Linux's `vulnerabilities/` directory, so this always
+    /// returns an empty vec on macOS.
+    pub fn vulnerabilities() -> Vec<Vulnerability> {
+        Vec::new()
+    }
+
+    /// Reads the idle states (C-states) the kernel can put cpu0 into.
+    ///
+    /// Only read from Linux's `cpuidle`, so this always returns an empty vec
+    /// on macOS.
+    pub fn idle_states() -> Vec<IdleState> {
+        Vec::new()
+    }
+
+    /// Returns the number of CPUs this process can effectively use.
+    ///
+    /// Cgroup CPU quotas are Linux-only, so on macOS this is
+    /// `total_logical_cores`.
+    pub fn available_parallelism(&self) -> Option<usize> {
+        self.total_logical_cores
+    }
+
+    /// Returns the logical cores the current process is allowed to run on.
+    ///
+    /// The affinity mask is only read on Linux, so this always returns an
+    /// empty vec on macOS.
+    pub fn allowed_cores() -> Vec<u32> {
+        Vec::new()
+    }
+
+    /// Detects the CPU architecture.
+    ///
+    /// `hw.optional.arm64` is checked first so that a binary running under
+    /// Rosetta 2 still reports the real Apple Silicon architecture.
+    fn get_architecture_macos() -> CpuArchitecture {
+        match Self::sysctl_u32("hw.optional.arm64") {
+            Ok(1) => CpuArchitecture::ARM64,
+            _ => Self::get_architecture(),
+        }
+    }
+
+    /// Detects the CPU manufacturer/vendor.
+    ///
+    /// Intel Macs use CPUID like every other platform; Apple Silicon has no
+    /// vendor string, so any ARM64 machine is reported as Apple.
+    fn get_fabricant_macos() -> Result<Fabricant, CpuInfoError> {
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            if !matches!(Self::get_architecture_macos(), CpuArchitecture::ARM64) {
+                return Self::get_fabricant_cpuid();
+            }
+        }
+
+        Ok(Fabricant::Apple)
+    }
+
+    /// Reads the microcode revision of Intel Macs from `machdep.cpu.microcode_version`.
+    ///
+    /// Apple Silicon has no microcode revision and returns `None`.
+    fn get_microcode_macos() -> Option<u64> {
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            Self::sysctl_u32("machdep.cpu.microcode_version").ok().map(u64::from)
+        }
+
+        #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+        {
+            None
+        }
+    }
+
+    /// Detects instruction-set extensions.
+    ///
+    /// On x86/x86_64, this uses CPUID; on Apple Silicon `hw.optional.neon`.
+    fn get_features_macos() -> Vec<CpuFeature> {
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            Self::get_features_cpuid()
+        }
+
+        #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+        {
+            match Self::sysctl_u32("hw.optional.neon") {
+                Ok(1) => vec![CpuFeature::Neon],
+                _ => Vec::new(),
+            }
+        }
+    }
+
+    /// Detects the CPU model name.
+    ///
+    /// On x86/x86_64, this uses CPUID for consistency with other platforms.
+    /// Falls back to `machdep.cpu.brand_string` otherwise.
+    fn get_model_macos() -> Result<String, CpuInfoError> {
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            if let Ok(model) = Self::get_model_cpuid() {
+                return Ok(model);
+            }
+        }
+
+        Self::sysctl_string("machdep.cpu.brand_string")
+    }
+
+    /// Detects CPU core distribution using the performance levels.
+    ///
+    /// Apple Silicon reports one `hw.perflevelN` entry per core type
+    /// (level 0 being the fastest). More than one level means the CPU is
+    /// hybrid, even though the kernel does not expose per-core frequencies.
+    /// Logical IDs are assigned level by level, starting with level 0.
+    fn get_distribution_macos() -> DistributionCore {
+        let max_mhz = Self::sysctl_u64("hw.cpufrequency_max")
+            .map(|hz| (hz / 1_000_000) as u32)
+            .unwrap_or(0);
+
+        let levels = Self::sysctl_u32("hw.nperflevels").unwrap_or(1);
+        if levels <= 1 {
+            // Single core type (Intel Macs): all cores share the same clock
+            let count = Self::sysctl_u32("hw.logicalcpu").unwrap_or(0);
+            let cores: Vec<Core> = (0..count)
+                .map(|id| Core::new(id, max_mhz, None))
+                .collect();
+            return Self::detect_distribution(&cores);
+        }
+
+        let mut cores = Vec::new();
+        for level in 0..levels {
+            let Ok(count) = Self::sysctl_u32(&format!("hw.perflevel{}.logicalcpu", level)) else {
+                continue;
+            };
+
+            // Level 0 holds the performance cores, higher levels the efficiency cores
+            let core_type = if level == 0 {
+                CoreType::Performance
+            } else {
+                CoreType::Efficient
+            };
+
+            for _ in 0..count {
+                let id = cores.len() as u32;
+                // Apple Silicon has no SMT, so every logical core is a physical core
+                let mut core = Core::new(id, max_mhz, Some(id));
+                core.core_type = Some(core_type);
+                cores.push(core);
+            }
+        }
+
+        if cores.is_empty() {
+            return DistributionCore::Lineal { mhz: max_mhz, base_mhz: 0 };
+        }
+
+        DistributionCore::Hybrid { groups: cores }
+    }
+}
@@ -0,0 +1,265 @@
+use crate::{CpuFeature,CpuInfo,CpuInfoError,DistributionCore,Fabricant,IdleState,SmtControl,Vulnerability};
+
+#[cfg(feature = "netbsd")]
+impl CpuInfo {
+    /// Creates a new `CpuInfo` instance by detecting all CPU information.
+    ///
+    /// On NetBSD every value is queried through `sysctlbyname`, so no files
+    /// or external commands are involved.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cpu_info::{CpuInfo, DistributionCore};
+    ///
+    /// let cpu_info = CpuInfo::new();
+    /// match cpu_info.distribution {
+    ///     DistributionCore::Lineal { mhz, .. } => {
+    ///         println!("Uniform CPU with all cores at {} MHz", mhz);
+    ///     }
+    ///     DistributionCore::Hybrid { ref groups } => {
+    ///         println!("Hybrid CPU with {} cores at different speeds", groups.len());
+    ///     }
+    /// }
+    /// ```
+    pub fn new() -> Self {
+        if let Some(Ok(info)) = Self::from_override() {
+            return info;
+        }
+
+        let total_logical_cores = Self::sysctl_u32("hw.ncpu").ok().map(|n| n as usize);
+
+        Self {
+            architecture: Self::get_architecture(),
+            endianness: Self::get_endianness(),
+            fabricant: Self::get_fabricant_netbsd().unwrap_or(Fabricant::Unknown),
+            model: Self::get_model_netbsd().unwrap_or("Unknown".to_string()),
+            total_logical_cores,
+            online_logical_cores: Self::sysctl_u32("hw.ncpuonline").ok().map(|n| n as usize),
+            total_physical_cores: None,
+            total_sockets: None,
+            smt_enabled: None,
+            distribution: Self::get_distribution_netbsd(),
+            caches: Self::get_caches(),
+            features: Self::get_features_netbsd(),
+            signature: Self::get_signature(),
+            microcode: None,
+            address_bits: Self::get_address_bits(),
+            hypervisor: Self::get_hypervisor(),
+            numa_nodes: Vec::new(),
+            clusters: Vec::new(),
+        }
+    }
+
+    /// Creates a new `CpuInfo` instance, reporting why detection failed.
+    ///
+    /// Unlike [`CpuInfo::new`], this returns [`CpuInfoError::Os`] when one of
+    /// the required `sysctl` values cannot be read.
+    pub fn try_new() -> Result<Self, CpuInfoError> {
+        if let Some(result) = Self::from_override() {
+            return result;
+        }
+
+        let total_logical_cores = Some(Self::sysctl_u32("hw.ncpu")? as usize);
+
+        Ok(Self {
+            architecture: Self::get_architecture(),
+            endianness: Self::get_endianness(),
+            fabricant: Self::get_fabricant_netbsd()?,
+            model: Self::get_model_netbsd()?,
+            total_logical_cores,
+            online_logical_cores: Self::sysctl_u32("hw.ncpuonline").ok().map(|n| n as usize),
+            total_physical_cores: None,
+            total_sockets: None,
+            smt_enabled: None,
+            distribution: Self::get_distribution_netbsd(),
+            caches: Self::get_caches(),
+            features: Self::get_features_netbsd(),
+            signature: Self::get_signature(),
+            microcode: None,
+            address_bits: Self::get_address_bits(),
+            hypervisor: Self::get_hypervisor(),
+            numa_nodes: Vec::new(),
+            clusters: Vec::new(),
+        })
+    }
+
+    /// Re-reads the current frequency of every known core.
+    ///
+    /// NetBSD reports a single system-wide frequency, so there are no
+    /// per-core values to refresh and this is a no-op.
+    pub fn refresh_frequencies(&mut self) {}
+
+    /// Re-reads how many logical cores are online.
+    ///
+    /// Only `hw.ncpuonline` is queried again; every other field is left untouched.
+    pub fn refresh_online(&mut self) {
+        self.online_logical_cores = Self::sysctl_u32("hw.ncpuonline").ok().map(|n| n as usize);
+    }
+
+    /// Reads the current CPU package temperature in degrees Celsius.
+    ///
+    /// NetBSD exposes temperatures through `envsys(4)` rather than `sysctl`,
+    /// so this always returns `None`.
+    pub fn package_temperature_celsius() -> Option<f32> {
+        None
+    }
+
+    /// Reads the configured package power limit in watts.
+    ///
+    /// NetBSD has no `sysctl` for the package power limit, so this always returns
+    /// `None`.
+    pub fn power_limit_watts() -> Option<f64> {
+        None
+    }
+
+    /// Returns `true` if every core uses the `performance` cpufreq governor.
+    ///
+    /// cpufreq governors are Linux-only, so this always returns `false` on
+    /// NetBSD.
+    pub fn all_cores_performance() -> bool {
+        false
+    }
+
+    /// Returns `true` if any core exposes a Linux `cpufreq` directory.
+    ///
+    /// Always `false` on NetBSD.
+    pub fn has_cpufreq() -> bool {
+        false
+    }
+
+    /// Returns the active cpufreq scaling driver.
+    ///
+    /// Always `None` on NetBSD, which has no cpufreq drivers.
+    pub fn scaling_driver() -> Option<String> {
+        None
+    }
+
+    /// Reads whether SMT (Hyper-Threading) can be switched at runtime.
+    ///
+    /// Only read from Linux's `smt/control`; always `None` on NetBSD.
+    pub fn smt_control() -> Option<SmtControl> {
+        None
+    }
+
+    /// Returns whether turbo boost is currently enabled.
+    ///
+    /// Only read from Linux's `intel_pstate` and `cpufreq` controls; always
+    /// `None` on NetBSD.
+    pub fn turbo_enabled() -> Option<bool> {
+        None
+    }
+
+    /// Returns the logical cores isolated from the scheduler with `isolcpus`.
+    ///
+    /// `isolcpus` is a Linux boot option, so this always returns an empty vec
+    /// on NetBSD.
+    pub fn isolated_cores() -> Vec<u32> {
+        Vec::new()
+    }
+
+    /// Returns the groups of logical cores sharing a cache at `level`.
+    ///
+    /// Sharing lists are only read from Linux sysfs, so this always returns
+    /// an empty vec on NetBSD.
+    pub fn cores_sharing_cache(_level: u8) -> Vec<Vec<u32>> {
+        Vec::new()
+    }
+
+    /// Reads the kernel's status for every known CPU vulnerability.
+    ///
+    /// Only read from Linux's `vulnerabilities/` directory, so this always
+    /// returns an empty vec on NetBSD.
+    pub fn vulnerabilities() -> Vec<Vulnerability> {
+        Vec::new()
+    }
+
+    /// Reads the idle states (C-states) the kernel can put cpu0 into.
+    ///
+    /// Only read from Linux's `cpuidle`, so this always returns an empty vec
+    /// on NetBSD.
+    pub fn idle_states() -> Vec<IdleState> {
+        Vec::new()
+    }
+
+    /// Returns the number of CPUs this process can effectively use.
+    ///
+    /// Cgroup CPU quotas are Linux-only, so on NetBSD this is
+    /// `total_logical_cores`.
+    pub fn available_parallelism(&self) -> Option<usize> {
+        self.total_logical_cores
+    }
+
+    /// Returns the logical cores the current process is allowed to run on.
+    ///
+    /// The affinity mask is only read on Linux, so this always returns an
+    /// empty vec on NetBSD.
+    pub fn allowed_cores() -> Vec<u32> {
+        Vec::new()
+    }
+
+    /// Detects the CPU manufacturer/vendor.
+    ///
+    /// On x86/x86_64, this uses CPUID like every other platform.
+    /// Other architectures don't expose a vendor through sysctl.
+    fn get_fabricant_netbsd() -> Result<Fabricant, CpuInfoError> {
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            Self::get_fabricant_cpuid()
+        }
+
+        #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+        {
+            Ok(Fabricant::Unknown)
+        }
+    }
+
+    /// Detects the CPU model name.
+    ///
+    /// On x86/x86_64, this uses CPUID for consistency with other platforms.
+    /// Falls back to `hw.model` otherwise.
+    fn get_model_netbsd() -> Result<String, CpuInfoError> {
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            if let Ok(model) = Self::get_model_cpuid() {
+                return Ok(model);
+            }
+        }
+
+        Self::sysctl_string("hw.model")
+    }
+
+    /// Detects instruction-set extensions.
+    ///
+    /// On x86/x86_64, this uses CPUID. Other architectures report no features yet.
+    fn get_features_netbsd() -> Vec<CpuFeature> {
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            Self::get_features_cpuid()
+        }
+
+        #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+        {
+            Vec::new()
+        }
+    }
+
+    /// Detects the CPU frequency.
+    ///
+    /// `machdep.cpu.frequency.available` lists the supported frequencies in
+    /// MHz (`"2400 1600 800"`) when a frequency driver such as `est(4)` is
+    /// attached. NetBSD has no per-core values, so the result is always `Lineal`.
+    fn get_distribution_netbsd() -> DistributionCore {
+        let mhz = Self::sysctl_string("machdep.cpu.frequency.available")
+            .ok()
+            .and_then(|levels| {
+                levels
+                    .split_whitespace()
+                    .filter_map(|level| level.parse::<u32>().ok())
+                    .max()
+            })
+            .unwrap_or(0);
+
+        DistributionCore::Lineal { mhz, base_mhz: 0 }
+    }
+}
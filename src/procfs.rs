@@ -0,0 +1,289 @@
+use alloc::collections::BTreeSet;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use crate::{AddressBits, Core, CpuArchitecture, CpuFeature, CpuInfo, Endianness, Fabricant, Signature};
+
+/// Parsing of `/proc/cpuinfo` text.
+///
+/// Pure string handling, so it also works on captured dumps and without `std`.
+impl CpuInfo {
+    /// Builds a `CpuInfo` from the text of a captured `/proc/cpuinfo`.
+    ///
+    /// Useful for analyzing dumps collected on other machines. Only what the
+    /// text reports is filled in:
+    ///
+    /// - `model` from `model name`, `fabricant` from `vendor_id`
+    /// - `architecture` from the `lm` flag (x86), `CPU architecture` (ARM)
+    ///   or `isa` (RISC-V)
+    /// - core and socket counts from the `processor`, `core id` and
+    ///   `physical id` entries
+    /// - per-core frequencies from `cpu MHz`, which is the current clock at
+    ///   capture time and is used as the maximum too
+    /// - `features`, `signature`, `microcode` and `address_bits` on x86
+    ///
+    /// Everything else keeps the placeholder of [`CpuInfo::default`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cpu_info::{CpuArchitecture, CpuFeature, CpuInfo, Fabricant};
+    ///
+    /// let dump = "\
+    /// processor\t: 0
+    /// vendor_id\t: GenuineIntel
+    /// cpu family\t: 6
+    /// model\t\t: 158
+    /// model name\t: Intel(R) Core(TM) i7-8700K CPU @ 3.70GHz
+    /// stepping\t: 10
+    /// microcode\t: 0xf0
+    /// cpu MHz\t\t: 3700.000
+    /// physical id\t: 0
+    /// core id\t\t: 0
+    /// flags\t\t: fpu sse sse2 lm avx avx2
+    ///
+    /// processor\t: 1
+    /// vendor_id\t: GenuineIntel
+    /// model name\t: Intel(R) Core(TM) i7-8700K CPU @ 3.70GHz
+    /// cpu MHz\t\t: 3700.000
+    /// physical id\t: 0
+    /// core id\t\t: 0
+    /// ";
+    ///
+    /// let info = CpuInfo::from_proc_cpuinfo_str(dump);
+    /// assert_eq!(info.model, "Intel(R) Core(TM) i7-8700K CPU @ 3.70GHz");
+    /// assert_eq!(info.fabricant, Fabricant::Intel);
+    /// assert_eq!(info.architecture, CpuArchitecture::X86_64);
+    /// assert_eq!(info.total_logical_cores, Some(2));
+    /// assert_eq!(info.total_physical_cores, Some(1));
+    /// assert_eq!(info.smt_enabled, Some(true));
+    /// assert_eq!(info.max_frequency_mhz(), 3700);
+    /// assert!(info.has_feature(CpuFeature::Avx2));
+    /// assert_eq!(info.signature.unwrap().model, 158);
+    /// assert_eq!(info.microcode, Some(0xf0));
+    /// ```
+    pub fn from_proc_cpuinfo_str(s: &str) -> CpuInfo {
+        let cores = Self::parse_cpuinfo_cores(s);
+        let blocks: Vec<&str> = s
+            .split("\n\n")
+            .filter(|block| Self::cpuinfo_field(block, "processor").is_some())
+            .collect();
+
+        let sockets: BTreeSet<&str> = blocks
+            .iter()
+            .filter_map(|block| Self::cpuinfo_field(block, "physical id"))
+            .collect();
+        let physical_cores: BTreeSet<(Option<&str>, &str)> = blocks
+            .iter()
+            .filter_map(|block| {
+                let core_id = Self::cpuinfo_field(block, "core id")?;
+                Some((Self::cpuinfo_field(block, "physical id"), core_id))
+            })
+            .collect();
+
+        let count = |n: usize| (n > 0).then_some(n);
+        let total_logical_cores = count(blocks.len());
+        let total_physical_cores = count(physical_cores.len());
+
+        let signature = match (
+            Self::cpuinfo_field(s, "cpu family").and_then(|v| v.parse::<u16>().ok()),
+            Self::cpuinfo_field(s, "model").and_then(|v| v.parse::<u16>().ok()),
+            Self::cpuinfo_field(s, "stepping").and_then(|v| v.parse::<u8>().ok()),
+        ) {
+            (Some(family), Some(model), Some(stepping)) => Some(Signature { family, model, stepping }),
+            _ => None,
+        };
+
+        CpuInfo {
+            architecture: Self::parse_cpuinfo_architecture(s),
+            endianness: Endianness::Little,
+            fabricant: Self::parse_cpuinfo_fabricant(s),
+            model: Self::parse_cpuinfo_model(s).unwrap_or("Unknown").to_string(),
+            total_logical_cores,
+            online_logical_cores: total_logical_cores,
+            total_physical_cores,
+            total_sockets: count(sockets.len()),
+            smt_enabled: Self::smt_from_counts(total_logical_cores, total_physical_cores),
+            distribution: Self::detect_distribution(&cores),
+            features: Self::parse_cpuinfo_features(s),
+            signature,
+            microcode: Self::parse_cpuinfo_microcode(s),
+            address_bits: Self::parse_cpuinfo_address_bits(s),
+            ..CpuInfo::default()
+        }
+    }
+
+    /// Returns the trimmed value of the first `key : value` line with exactly this key.
+    pub(crate) fn cpuinfo_field<'a>(text: &'a str, key: &str) -> Option<&'a str> {
+        text.lines().find_map(|line| {
+            let (k, value) = line.split_once(':')?;
+            (k.trim() == key).then(|| value.trim())
+        })
+    }
+
+    /// Reads the vendor from the `vendor_id` line (e.g. `GenuineIntel`).
+    pub(crate) fn parse_cpuinfo_fabricant(content: &str) -> Fabricant {
+        Self::cpuinfo_field(content, "vendor_id").map_or(Fabricant::Unknown, Self::fabricant_from_vendor_id)
+    }
+
+    /// Reads the `model name` line, comparing the key case-insensitively.
+    ///
+    /// LoongArch kernels that print no model name still report `CPU Family`
+    /// (e.g. `Loongson-64bit`), which is used instead.
+    pub(crate) fn parse_cpuinfo_model(content: &str) -> Option<&str> {
+        content
+            .lines()
+            .find_map(|line| {
+                let (key, value) = line.split_once(':')?;
+                key.trim().eq_ignore_ascii_case("model name").then(|| value.trim())
+            })
+            // x86 prints a numeric "cpu family", so only LoongArch's capitalized key matches
+            .or_else(|| Self::cpuinfo_field(content, "CPU Family"))
+    }
+
+    /// Reads the `microcode` line (e.g. `microcode : 0xf0`), only present on x86.
+    pub(crate) fn parse_cpuinfo_microcode(content: &str) -> Option<u64> {
+        Self::cpuinfo_field(content, "microcode").and_then(Self::parse_microcode_revision)
+    }
+
+    /// Parses a microcode revision, written in hex with or without `0x`.
+    pub(crate) fn parse_microcode_revision(value: &str) -> Option<u64> {
+        let value = value.trim();
+        u64::from_str_radix(value.strip_prefix("0x").unwrap_or(value), 16).ok()
+    }
+
+    /// Reads one `Core` per processor block.
+    ///
+    /// `cpu MHz` is the current frequency (e.g. `"3592.968"`), so it is
+    /// rounded and used as both the maximum and current speed. Logical cores
+    /// with the same `physical id` and `core id` are recorded as siblings.
+    pub(crate) fn parse_cpuinfo_cores(content: &str) -> Vec<Core> {
+        let cores: Vec<(Option<&str>, Core)> = content
+            .split("\n\n")
+            .filter_map(|block| {
+                // Blocks without a processor number (e.g. ARM's trailing "Hardware" block)
+                let id = Self::cpuinfo_field(block, "processor")?.parse::<u32>().ok()?;
+                let mhz = Self::cpuinfo_field(block, "cpu MHz")
+                    .and_then(|v| v.parse::<f64>().ok())
+                    .map_or(0, |mhz| (mhz + 0.5) as u32);
+                let physical_core_id = Self::cpuinfo_field(block, "core id")
+                    .and_then(|v| v.parse::<u32>().ok());
+
+                let mut core = Core::new(id, mhz, physical_core_id);
+                core.current_mhz = mhz;
+                Some((Self::cpuinfo_field(block, "physical id"), core))
+            })
+            .collect();
+
+        cores
+            .iter()
+            .map(|(package, core)| {
+                let mut core = core.clone();
+                if core.physical_core_id.is_some() {
+                    core.siblings = cores
+                        .iter()
+                        .filter(|(p, c)| p == package && c.physical_core_id == core.physical_core_id)
+                        .map(|(_, c)| c.id)
+                        .collect();
+                    core.siblings.sort_unstable();
+                }
+                core
+            })
+            .collect()
+    }
+
+    /// Detects the architecture from architecture-specific fields.
+    fn parse_cpuinfo_architecture(content: &str) -> CpuArchitecture {
+        if let Some(isa) = Self::cpuinfo_field(content, "isa") {
+            if isa.starts_with("rv64") {
+                return CpuArchitecture::RISCV64;
+            }
+            if isa.starts_with("rv32") {
+                return CpuArchitecture::RISCV32;
+            }
+        }
+
+        if let Some(arch) = Self::cpuinfo_field(content, "CPU architecture") {
+            // AArch64 kernels report 8 (or "AArch64" on old ones), 32-bit kernels 7 or lower
+            return match arch.parse::<u32>() {
+                Ok(8..) => CpuArchitecture::ARM64,
+                Err(_) if arch.starts_with("AArch64") => CpuArchitecture::ARM64,
+                _ => CpuArchitecture::ARM,
+            };
+        }
+
+        match Self::cpuinfo_field(content, "flags") {
+            Some(flags) if flags.split_whitespace().any(|flag| flag == "lm") => CpuArchitecture::X86_64,
+            Some(_) => CpuArchitecture::X86,
+            None => CpuArchitecture::Unknown,
+        }
+    }
+
+    /// Maps the `flags` (x86) or `Features` (ARM) line to `CpuFeature`s.
+    ///
+    /// AArch64 kernels report NEON as `asimd`, 32-bit ARM kernels as `neon`.
+    pub(crate) fn parse_cpuinfo_features(content: &str) -> Vec<CpuFeature> {
+        let Some(flags) = content.lines().find_map(|line| {
+            let (key, value) = line.split_once(':')?;
+            let key = key.trim();
+            (key.eq_ignore_ascii_case("flags") || key.eq_ignore_ascii_case("features")).then_some(value)
+        }) else {
+            return Vec::new();
+        };
+
+        let flags: BTreeSet<&str> = flags.split_whitespace().collect();
+        let checks = [
+            (CpuFeature::Sse, &["sse"][..]),
+            (CpuFeature::Sse2, &["sse2"]),
+            (CpuFeature::Sse3, &["pni"]),
+            (CpuFeature::Ssse3, &["ssse3"]),
+            (CpuFeature::Sse4_1, &["sse4_1"]),
+            (CpuFeature::Sse4_2, &["sse4_2"]),
+            (CpuFeature::Popcnt, &["popcnt"]),
+            (CpuFeature::Aes, &["aes"]),
+            (CpuFeature::Avx, &["avx"]),
+            (CpuFeature::Avx2, &["avx2"]),
+            (CpuFeature::Fma, &["fma"]),
+            (CpuFeature::Bmi1, &["bmi1"]),
+            (CpuFeature::Bmi2, &["bmi2"]),
+            (CpuFeature::Avx512F, &["avx512f"]),
+            (CpuFeature::Avx512Dq, &["avx512dq"]),
+            (CpuFeature::Avx512Bw, &["avx512bw"]),
+            (CpuFeature::Avx512Vl, &["avx512vl"]),
+            (CpuFeature::Avx512Vbmi, &["avx512vbmi"]),
+            (CpuFeature::Avx512Vnni, &["avx512_vnni"]),
+            (CpuFeature::Avx512Bf16, &["avx512_bf16"]),
+            (CpuFeature::Rdrand, &["rdrand"]),
+            (CpuFeature::Rdseed, &["rdseed"]),
+            (CpuFeature::Neon, &["asimd", "neon"]),
+            (CpuFeature::Sve, &["sve"]),
+        ];
+
+        checks
+            .into_iter()
+            .filter(|(_, names)| names.iter().any(|name| flags.contains(name)))
+            .map(|(feature, _)| feature)
+            .collect()
+    }
+
+    /// Reads the `address sizes` line.
+    ///
+    /// The line looks like `address sizes : 39 bits physical, 48 bits virtual`
+    /// and is only present on x86.
+    pub(crate) fn parse_cpuinfo_address_bits(content: &str) -> Option<AddressBits> {
+        let value = Self::cpuinfo_field(content, "address sizes")?;
+
+        let mut physical = None;
+        let mut virtual_ = None;
+        for part in value.split(',') {
+            let mut words = part.split_whitespace();
+            let bits = words.next()?.parse::<u8>().ok()?;
+            match words.last() {
+                Some("physical") => physical = Some(bits),
+                Some("virtual") => virtual_ = Some(bits),
+                _ => {}
+            }
+        }
+
+        Some(AddressBits { physical: physical?, virtual_: virtual_? })
+    }
+}
@@ -0,0 +1,152 @@
+#[cfg(not(target_os = "openbsd"))]
+use std::ffi::CString;
+use std::io;
+use std::ptr;
+use crate::{CpuInfo,CpuInfoError};
+
+/// `sysctl` wrappers shared by the BSD-family platform modules.
+///
+/// OpenBSD has no `sysctlbyname`, so it gets MIB-based variants instead.
+impl CpuInfo {
+    /// Reads a string value with `sysctlbyname`.
+    #[cfg(not(target_os = "openbsd"))]
+    pub(crate) fn sysctl_string(name: &str) -> Result<String, CpuInfoError> {
+        let name = CString::new(name)
+            .map_err(|e| CpuInfoError::ParseError(e.to_string()))?;
+
+        let mut len: libc::size_t = 0;
+        // First call only queries the required buffer length
+        let ret = unsafe {
+            libc::sysctlbyname(name.as_ptr(), ptr::null_mut(), &mut len, ptr::null_mut(), 0)
+        };
+        if ret != 0 {
+            return Err(CpuInfoError::Os(io::Error::last_os_error()));
+        }
+
+        let mut buf = vec![0u8; len];
+        let ret = unsafe {
+            libc::sysctlbyname(name.as_ptr(), buf.as_mut_ptr().cast(), &mut len, ptr::null_mut(), 0)
+        };
+        if ret != 0 {
+            return Err(CpuInfoError::Os(io::Error::last_os_error()));
+        }
+
+        buf.truncate(len);
+        let value = String::from_utf8_lossy(&buf)
+            .trim_end_matches('\0')
+            .trim()
+            .to_string();
+
+        if value.is_empty() {
+            Err(CpuInfoError::Os(io::Error::new(io::ErrorKind::NotFound, "Empty sysctl value")))
+        } else {
+            Ok(value)
+        }
+    }
+
+    /// Reads a 32-bit integer value with `sysctlbyname`.
+    #[cfg(not(target_os = "openbsd"))]
+    pub(crate) fn sysctl_u32(name: &str) -> Result<u32, CpuInfoError> {
+        let name = CString::new(name)
+            .map_err(|e| CpuInfoError::ParseError(e.to_string()))?;
+
+        let mut value: u32 = 0;
+        let mut len = std::mem::size_of::<u32>();
+        let ret = unsafe {
+            libc::sysctlbyname(
+                name.as_ptr(),
+                (&mut value as *mut u32).cast(),
+                &mut len,
+                ptr::null_mut(),
+                0,
+            )
+        };
+
+        if ret != 0 {
+            Err(CpuInfoError::Os(io::Error::last_os_error()))
+        } else {
+            Ok(value)
+        }
+    }
+
+    /// Reads a 64-bit integer value with `sysctlbyname`.
+    #[cfg(target_os = "macos")]
+    pub(crate) fn sysctl_u64(name: &str) -> Result<u64, CpuInfoError> {
+        let name = CString::new(name)
+            .map_err(|e| CpuInfoError::ParseError(e.to_string()))?;
+
+        let mut value: u64 = 0;
+        let mut len = std::mem::size_of::<u64>();
+        let ret = unsafe {
+            libc::sysctlbyname(
+                name.as_ptr(),
+                (&mut value as *mut u64).cast(),
+                &mut len,
+                ptr::null_mut(),
+                0,
+            )
+        };
+
+        if ret != 0 {
+            Err(CpuInfoError::Os(io::Error::last_os_error()))
+        } else {
+            Ok(value)
+        }
+    }
+
+    /// Reads a string value with `sysctl` and a MIB such as `[CTL_HW, HW_MODEL]`.
+    #[cfg(target_os = "openbsd")]
+    pub(crate) fn sysctl_mib_string(mib: &[libc::c_int]) -> Result<String, CpuInfoError> {
+        let mut len: libc::size_t = 0;
+        // First call only queries the required buffer length
+        let ret = unsafe {
+            libc::sysctl(mib.as_ptr(), mib.len() as libc::c_uint, ptr::null_mut(), &mut len, ptr::null_mut(), 0)
+        };
+        if ret != 0 {
+            return Err(CpuInfoError::Os(io::Error::last_os_error()));
+        }
+
+        let mut buf = vec![0u8; len];
+        let ret = unsafe {
+            libc::sysctl(mib.as_ptr(), mib.len() as libc::c_uint, buf.as_mut_ptr().cast(), &mut len, ptr::null_mut(), 0)
+        };
+        if ret != 0 {
+            return Err(CpuInfoError::Os(io::Error::last_os_error()));
+        }
+
+        buf.truncate(len);
+        let value = String::from_utf8_lossy(&buf)
+            .trim_end_matches('\0')
+            .trim()
+            .to_string();
+
+        if value.is_empty() {
+            Err(CpuInfoError::Os(io::Error::new(io::ErrorKind::NotFound, "Empty sysctl value")))
+        } else {
+            Ok(value)
+        }
+    }
+
+    /// Reads a 32-bit integer value with `sysctl` and a MIB.
+    #[cfg(target_os = "openbsd")]
+    pub(crate) fn sysctl_mib_u32(mib: &[libc::c_int]) -> Result<u32, CpuInfoError> {
+        let mut value: u32 = 0;
+        let mut len = std::mem::size_of::<u32>();
+        let ret = unsafe {
+            libc::sysctl(
+                mib.as_ptr(),
+                mib.len() as libc::c_uint,
+                (&mut value as *mut u32).cast(),
+                &mut len,
+                ptr::null_mut(),
+                0,
+            )
+        };
+
+        if ret != 0 {
+            Err(CpuInfoError::Os(io::Error::last_os_error()))
+        } else {
+            Ok(value)
+        }
+    }
+}
@@ -0,0 +1,167 @@
+use crate::{CpuInfo,CpuInfoError,DistributionCore,Fabricant,IdleState,SmtControl,Vulnerability};
+
+impl CpuInfo {
+    /// Creates a placeholder `CpuInfo` on WebAssembly.
+    ///
+    /// A WebAssembly module can't see the host CPU, so this only lets code
+    /// that depends on the crate compile and run: the model is `"wasm"`,
+    /// counts are `None` and the distribution is `Lineal` at 0 MHz.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cpu_info::CpuInfo;
+    ///
+    /// let cpu_info = CpuInfo::new();
+    /// assert_eq!(cpu_info.model, "wasm");
+    /// ```
+    pub fn new() -> Self {
+        if let Some(Ok(info)) = Self::from_override() {
+            return info;
+        }
+
+        Self {
+            architecture: Self::get_architecture(),
+            endianness: Self::get_endianness(),
+            fabricant: Fabricant::Unknown,
+            model: "wasm".to_string(),
+            total_logical_cores: None,
+            online_logical_cores: None,
+            total_physical_cores: None,
+            total_sockets: None,
+            smt_enabled: None,
+            distribution: DistributionCore::Lineal { mhz: 0, base_mhz: 0 },
+            caches: Self::get_caches(),
+            features: Vec::new(),
+            signature: Self::get_signature(),
+            microcode: None,
+            address_bits: Self::get_address_bits(),
+            hypervisor: Self::get_hypervisor(),
+            numa_nodes: Vec::new(),
+            clusters: Vec::new(),
+        }
+    }
+
+    /// Creates a placeholder `CpuInfo` on WebAssembly.
+    ///
+    /// There is nothing to detect, so this only fails on an unreadable
+    /// `CPU_INFO_OVERRIDE` file and otherwise returns the same value as
+    /// [`CpuInfo::new`].
+    pub fn try_new() -> Result<Self, CpuInfoError> {
+        if let Some(result) = Self::from_override() {
+            return result;
+        }
+
+        Ok(Self::new())
+    }
+
+    /// Re-reads the current frequency of every known core.
+    ///
+    /// WebAssembly reports no frequencies, so this is a no-op.
+    pub fn refresh_frequencies(&mut self) {}
+
+    /// Re-reads how many logical cores are online.
+    ///
+    /// WebAssembly reports no cores, so this is a no-op.
+    pub fn refresh_online(&mut self) {}
+
+    /// Reads the current CPU package temperature in degrees Celsius.
+    ///
+    /// WebAssembly has no access to sensors, so this always returns `None`.
+    pub fn package_temperature_celsius() -> Option<f32> {
+        None
+    }
+
+    /// Reads the configured package power limit in watts.
+    ///
+    /// WebAssembly has no access to power management, so this always returns
+    /// `None`.
+    pub fn power_limit_watts() -> Option<f64> {
+        None
+    }
+
+    /// Returns `true` if every core uses the `performance` cpufreq governor.
+    ///
+    /// cpufreq governors are Linux-only, so this always returns `false` on
+    /// WebAssembly.
+    pub fn all_cores_performance() -> bool {
+        false
+    }
+
+    /// Returns `true` if any core exposes a Linux `cpufreq` directory.
+    ///
+    /// Always `false` on WebAssembly.
+    pub fn has_cpufreq() -> bool {
+        false
+    }
+
+    /// Returns the active cpufreq scaling driver.
+    ///
+    /// Always `None` on WebAssembly, which has no cpufreq drivers.
+    pub fn scaling_driver() -> Option<String> {
+        None
+    }
+
+    /// Reads whether SMT (Hyper-Threading) can be switched at runtime.
+    ///
+    /// Only read from Linux's `smt/control`; always `None` on WebAssembly.
+    pub fn smt_control() -> Option<SmtControl> {
+        None
+    }
+
+    /// Returns whether turbo boost is currently enabled.
+    ///
+    /// Only read from Linux's `intel_pstate` and `cpufreq` controls; always
+    /// `None` on WebAssembly.
+    pub fn turbo_enabled() -> Option<bool> {
+        None
+    }
+
+    /// Returns the logical cores isolated from the scheduler with `isolcpus`.
+    ///
+    /// `isolcpus` is a Linux boot option, so this always returns an empty vec
+    /// on WebAssembly.
+    pub fn isolated_cores() -> Vec<u32> {
+        Vec::new()
+    }
+
+    /// Returns the groups of logical cores sharing a cache at `level`.
+    ///
+    /// Sharing lists are only read from Linux sysfs, so this always returns
+    /// an empty vec on WebAssembly.
+    pub fn cores_sharing_cache(_level: u8) -> Vec<Vec<u32>> {
+        Vec::new()
+    }
+
+    /// Reads the kernel's status for every known CPU vulnerability.
+    ///
+    /// Only read from Linux's `vulnerabilities/` directory, so this always
+    /// returns an empty vec on WebAssembly.
+    pub fn vulnerabilities() -> Vec<Vulnerability> {
+        Vec::new()
+    }
+
+    /// Reads the idle states (C-states) the kernel can put cpu0 into.
+    ///
+    /// Only read from Linux's `cpuidle`, so this always returns an empty vec
+    /// on WebAssembly.
+    pub fn idle_states() -> Vec<IdleState> {
+        Vec::new()
+    }
+
+    /// Returns the number of CPUs this process can effectively use.
+    ///
+    /// Cgroup CPU quotas are Linux-only, so on WebAssembly this is
+    /// `total_logical_cores`.
+    pub fn available_parallelism(&self) -> Option<usize> {
+        self.total_logical_cores
+    }
+
+    /// Returns the logical cores the current process is allowed to run on.
+    ///
+    /// The affinity mask is only read on Linux, so this always returns an
+    /// empty vec on WebAssembly.
+    pub fn allowed_cores() -> Vec<u32> {
+        Vec::new()
+    }
+}
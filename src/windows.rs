@@ -0,0 +1,435 @@
+use std::collections::HashSet;
+use std::io;
+use windows::core::{w, HSTRING, PCWSTR};
+use windows::Win32::Foundation::ERROR_SUCCESS;
+use windows::Win32::System::Registry::{RegGetValueW, HKEY_LOCAL_MACHINE, RRF_RT_REG_DWORD, RRF_RT_REG_SZ};
+use windows::Win32::System::SystemInformation::{
+    GetLogicalProcessorInformationEx, RelationProcessorCore, SYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX,
+};
+use crate::{Core,CoreType,CpuFeature,CpuInfo,CpuInfoError,Fabricant,IdleState,SmtControl,Vulnerability};
+
+/// Registry key holding one subkey per logical processor.
+const CENTRAL_PROCESSOR_KEY: &str = r"HARDWARE\DESCRIPTION\System\CentralProcessor";
+
+#[cfg(feature = "windows")]
+impl CpuInfo {
+    /// Creates a new `CpuInfo` instance by detecting all CPU information.
+    ///
+    /// Topology comes from `GetLogicalProcessorInformationEx`, the model name
+    /// and clock from the `CentralProcessor` registry key.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use cpu_info::{CpuInfo, DistributionCore};
+    ///
+    /// let cpu_info = CpuInfo::new();
+    /// match cpu_info.distribution {
+    ///     DistributionCore::Lineal { mhz, .. } => {
+    ///         println!("Uniform CPU with all cores at {} MHz", mhz);
+    ///     }
+    ///     DistributionCore::Hybrid { ref groups } => {
+    ///         println!("Hybrid CPU with {} cores at different speeds", groups.len());
+    ///     }
+    /// }
+    /// ```
+    pub fn new() -> Self {
+        if let Some(Ok(info)) = Self::from_override() {
+            return info;
+        }
+
+        let cores = Self::get_cores().unwrap_or_default();
+
+        let total_logical_cores = if cores.is_empty() {
+            None
+        } else {
+            Some(cores.len())
+        };
+
+        let physical_ids: HashSet<u32> = cores
+            .iter()
+            .filter_map(|core| core.physical_core_id)
+            .collect();
+        let total_physical_cores = if physical_ids.is_empty() {
+            None
+        } else {
+            Some(physical_ids.len())
+        };
+
+        let distribution = Self::detect_distribution(&cores);
+
+        Self {
+            architecture: Self::get_architecture(),
+            endianness: Self::get_endianness(),
+            fabricant: Self::get_fabricant().unwrap_or(Fabricant::Unknown),
+            model: Self::get_model().unwrap_or("Unknown".to_string()),
+            total_logical_cores,
+            // GetLogicalProcessorInformationEx only reports active processors
+            online_logical_cores: total_logical_cores,
+            total_physical_cores,
+            total_sockets: None,
+            smt_enabled: Self::smt_from_counts(total_logical_cores, total_physical_cores),
+            distribution,
+            caches: Self::get_caches(),
+            features: Self::get_features(),
+            signature: Self::get_signature(),
+            microcode: None,
+            address_bits: Self::get_address_bits(),
+            hypervisor: Self::get_hypervisor(),
+            numa_nodes: Vec::new(),
+            clusters: Vec::new(),
+        }
+    }
+
+    /// Creates a new `CpuInfo` instance, reporting why detection failed.
+    ///
+    /// Unlike [`CpuInfo::new`], this returns [`CpuInfoError::Os`] when the
+    /// processor topology or the registry cannot be queried.
+    pub fn try_new() -> Result<Self, CpuInfoError> {
+        if let Some(result) = Self::from_override() {
+            return result;
+        }
+
+        let cores = Self::get_cores()?;
+
+        let physical_ids: HashSet<u32> = cores
+            .iter()
+            .filter_map(|core| core.physical_core_id)
+            .collect();
+
+        Ok(Self {
+            architecture: Self::get_architecture(),
+            endianness: Self::get_endianness(),
+            fabricant: Self::get_fabricant()?,
+            model: Self::get_model()?,
+            total_logical_cores: Some(cores.len()),
+            online_logical_cores: Some(cores.len()),
+            total_physical_cores: Some(physical_ids.len()),
+            total_sockets: None,
+            smt_enabled: Some(cores.len() > physical_ids.len()),
+            distribution: Self::detect_distribution(&cores),
+            caches: Self::get_caches(),
+            features: Self::get_features(),
+            signature: Self::get_signature(),
+            microcode: None,
+            address_bits: Self::get_address_bits(),
+            hypervisor: Self::get_hypervisor(),
+            numa_nodes: Vec::new(),
+            clusters: Vec::new(),
+        })
+    }
+
+    /// Re-reads the current frequency of every known core.
+    ///
+    /// Windows only exposes the nominal `~MHz` value through the registry, so
+    /// there is no current frequency to refresh and this is a no-op.
+    pub fn refresh_frequencies(&mut self) {}
+
+    /// Re-reads how many logical cores are online.
+    ///
+    /// Windows reports only the processors available to the system, so
+    /// `online_logical_cores` mirrors the total and this is a no-op.
+    pub fn refresh_online(&mut self) {}
+
+    /// Reads the current CPU package temperature in degrees Celsius.
+    ///
+    /// Windows has no unprivileged temperature API, so this always returns `None`.
+    pub fn package_temperature_celsius() -> Option<f32> {
+        None
+    }
+
+    /// Reads the configured package power limit in watts.
+    ///
+    /// Windows only exposes power limits through vendor drivers, so this always
+    /// returns `None`.
+    pub fn power_limit_watts() -> Option<f64> {
+        None
+    }
+
+    /// Returns `true` if every core uses the `performance` cpufreq governor.
+    ///
+    /// cpufreq governors are Linux-only, so this always returns `false` on
+    /// Windows.
+    pub fn all_cores_performance() -> bool {
+        false
+    }
+
+    /// Returns `true` if any core exposes a Linux `cpufreq` directory.
+    ///
+    /// Always `false` on Windows.
+    pub fn has_cpufreq() -> bool {
+        false
+    }
+
+    /// Returns the active cpufreq scaling driver.
+    ///
+    /// Always `None` on Windows, which has no cpufreq drivers.
+    pub fn scaling_driver() -> Option<String> {
+        None
+    }
+
+    /// Reads whether SMT (Hyper-Threading) can be switched at runtime.
+    ///
+    /// Only read from Linux's `smt/control`; always `None` on Windows.
+    pub fn smt_control() -> Option<SmtControl> {
+        None
+    }
+
+    /// Returns whether turbo boost is currently enabled.
+    ///
+    /// Only read from Linux's `intel_pstate` and `cpufreq` controls; always
+    /// `None` on Windows.
+    pub fn turbo_enabled() -> Option<bool> {
+        None
+    }
+
+    /// Returns the logical cores isolated from the scheduler with `isolcpus`.
+    ///
+    /// `isolcpus` is a Linux boot option, so this always returns an empty vec
+    /// on Windows.
+    pub fn isolated_cores() -> Vec<u32> {
+        Vec::new()
+    }
+
+    /// Returns the groups of logical cores sharing a cache at `level`.
+    ///
+    /// Sharing lists are only read from Linux sysfs, so this always returns
+    /// an empty vec on Windows.
+    pub fn cores_sharing_cache(_level: u8) -> Vec<Vec<u32>> {
+        Vec::new()
+    }
+
+    /// Reads the kernel's status for every known CPU vulnerability.
+    ///
+    /// Only read from Linux's `vulnerabilities/` directory, so this always
+    /// returns an empty vec on Windows.
+    pub fn vulnerabilities() -> Vec<Vulnerability> {
+        Vec::new()
+    }
+
+    /// Reads the idle states (C-states) the kernel can put cpu0 into.
+    ///
+    /// Only read from Linux's `cpuidle`, so this always returns an empty vec
+    /// on Windows.
+    pub fn idle_states() -> Vec<IdleState> {
+        Vec::new()
+    }
+
+    /// Returns the number of CPUs this process can effectively use.
+    ///
+    /// Cgroup CPU quotas are Linux-only, so on Windows this is
+    /// `total_logical_cores`.
+    pub fn available_parallelism(&self) -> Option<usize> {
+        self.total_logical_cores
+    }
+
+    /// Returns the logical cores the current process is allowed to run on.
+    ///
+    /// The affinity mask is only read on Linux, so this always returns an
+    /// empty vec on Windows.
+    pub fn allowed_cores() -> Vec<u32> {
+        Vec::new()
+    }
+
+    /// Detects the CPU manufacturer/vendor.
+    ///
+    /// On x86/x86_64, this uses the CPUID instruction exactly like Linux.
+    /// On ARM64 the registry `VendorIdentifier` value is used instead.
+    fn get_fabricant() -> Result<Fabricant, CpuInfoError> {
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            Self::get_fabricant_cpuid()
+        }
+
+        #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+        {
+            let vendor = Self::read_registry_string(&format!("{}\\0", CENTRAL_PROCESSOR_KEY), w!("VendorIdentifier"))?;
+            // Snapdragon machines report "Qualcomm Technologies Inc"
+            if vendor.starts_with("Qualcomm") {
+                return Ok(Fabricant::Qualcomm);
+            }
+            Ok(Self::fabricant_from_vendor_id(&vendor))
+        }
+    }
+
+    /// Detects instruction-set extensions.
+    ///
+    /// On x86/x86_64, this uses CPUID exactly like Linux.
+    /// Other architectures report no features yet.
+    fn get_features() -> Vec<CpuFeature> {
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            Self::get_features_cpuid()
+        }
+
+        #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+        {
+            Vec::new()
+        }
+    }
+
+    /// Reads the CPU model name from the registry.
+    ///
+    /// Falls back to CPUID on x86/x86_64 if the registry value is missing.
+    fn get_model() -> Result<String, CpuInfoError> {
+        let model = Self::read_registry_string(&format!("{}\\0", CENTRAL_PROCESSOR_KEY), w!("ProcessorNameString"));
+
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            if model.is_err() {
+                return Self::get_model_cpuid();
+            }
+        }
+
+        model
+    }
+
+    /// Reads detailed information for all CPU cores.
+    ///
+    /// Each `RelationProcessorCore` record describes one physical core and
+    /// the logical processors (bits of its group masks) that belong to it.
+    /// Logical IDs are numbered `group * 64 + bit`, matching Windows'
+    /// processor-group numbering.
+    fn get_cores() -> Result<Vec<Core>, CpuInfoError> {
+        let mut len: u32 = 0;
+
+        // First call only queries the required buffer length
+        let _ = unsafe { GetLogicalProcessorInformationEx(RelationProcessorCore, None, &mut len) };
+        if len == 0 {
+            return Err(CpuInfoError::Os(io::Error::last_os_error()));
+        }
+
+        // u64 storage keeps the records correctly aligned
+        let mut buffer = vec![0u64; (len as usize).div_ceil(8)];
+        unsafe {
+            GetLogicalProcessorInformationEx(
+                RelationProcessorCore,
+                Some(buffer.as_mut_ptr().cast()),
+                &mut len,
+            )
+        }
+        .map_err(|e| CpuInfoError::Os(io::Error::other(e.message())))?;
+
+        let bytes = buffer.as_ptr().cast::<u8>();
+        let mut cores = Vec::new();
+        let mut offset = 0usize;
+        let mut physical_id = 0u32;
+
+        while offset < len as usize {
+            let info = unsafe { &*bytes.add(offset).cast::<SYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX>() };
+            if info.Size == 0 {
+                break;
+            }
+
+            if info.Relationship == RelationProcessorCore {
+                let processor = unsafe { &info.Anonymous.Processor };
+                // GroupMask is declared with one element but holds GroupCount entries
+                let masks = unsafe {
+                    std::slice::from_raw_parts(processor.GroupMask.as_ptr(), processor.GroupCount as usize)
+                };
+
+                for mask in masks {
+                    for bit in 0..usize::BITS {
+                        if mask.Mask & (1usize << bit) != 0 {
+                            let id = mask.Group as u32 * 64 + bit;
+                            let core = Core::new(id, Self::get_core_mhz(id), Some(physical_id));
+                            cores.push((core, processor.EfficiencyClass));
+                        }
+                    }
+                }
+
+                physical_id += 1;
+            }
+
+            offset += info.Size as usize;
+        }
+
+        // Hybrid CPUs report more than one efficiency class; the highest
+        // class belongs to the performance cores
+        let min_class = cores.iter().map(|(_, class)| *class).min().unwrap_or(0);
+        let max_class = cores.iter().map(|(_, class)| *class).max().unwrap_or(0);
+
+        let mut cores: Vec<Core> = cores
+            .into_iter()
+            .map(|(mut core, class)| {
+                if min_class != max_class {
+                    core.core_type = Some(if class == max_class {
+                        CoreType::Performance
+                    } else {
+                        CoreType::Efficient
+                    });
+                }
+                core
+            })
+            .collect();
+
+        cores.sort_by_key(|c| c.id);
+        Ok(cores)
+    }
+
+    /// Reads the nominal clock of a logical processor from the registry (`~MHz`).
+    ///
+    /// Returns 0 if the value is unavailable.
+    fn get_core_mhz(id: u32) -> u32 {
+        let subkey = HSTRING::from(format!("{}\\{}", CENTRAL_PROCESSOR_KEY, id));
+        let mut value: u32 = 0;
+        let mut size = std::mem::size_of::<u32>() as u32;
+
+        let status = unsafe {
+            RegGetValueW(
+                HKEY_LOCAL_MACHINE,
+                &subkey,
+                w!("~MHz"),
+                RRF_RT_REG_DWORD,
+                None,
+                Some((&mut value as *mut u32).cast()),
+                Some(&mut size),
+            )
+        };
+
+        if status == ERROR_SUCCESS {
+            value
+        } else {
+            0
+        }
+    }
+
+    /// Reads a `REG_SZ` value below `HKEY_LOCAL_MACHINE`.
+    fn read_registry_string(subkey: &str, value: PCWSTR) -> Result<String, CpuInfoError> {
+        let subkey = HSTRING::from(subkey);
+        let mut size: u32 = 0;
+
+        // First call only queries the required buffer size (in bytes)
+        let status = unsafe {
+            RegGetValueW(HKEY_LOCAL_MACHINE, &subkey, value, RRF_RT_REG_SZ, None, None, Some(&mut size))
+        };
+        if status != ERROR_SUCCESS {
+            return Err(CpuInfoError::Os(io::Error::from_raw_os_error(status.0 as i32)));
+        }
+
+        let mut buf = vec![0u16; (size as usize).div_ceil(2)];
+        let status = unsafe {
+            RegGetValueW(
+                HKEY_LOCAL_MACHINE,
+                &subkey,
+                value,
+                RRF_RT_REG_SZ,
+                None,
+                Some(buf.as_mut_ptr().cast()),
+                Some(&mut size),
+            )
+        };
+        if status != ERROR_SUCCESS {
+            return Err(CpuInfoError::Os(io::Error::from_raw_os_error(status.0 as i32)));
+        }
+
+        let text = String::from_utf16_lossy(&buf);
+        let text = text.trim_end_matches('\0').trim().to_string();
+
+        if text.is_empty() {
+            Err(CpuInfoError::Os(io::Error::new(io::ErrorKind::NotFound, "Empty registry value")))
+        } else {
+            Ok(text)
+        }
+    }
+}